@@ -0,0 +1,207 @@
+//! Generates the solution registry included by `src/template/registry.rs`.
+//!
+//! Every `src/bin/<year>-<day>.rs` binary that uses the `solution!` macro is compiled into the library as a module,
+//! so that `cargo all` and `cargo time` can run all puzzles in a single process.
+//!
+//! Also generates the example tests of every registered puzzle from the answers declared next to its example files,
+//! see [`write_example_tests`]. And records the compiler version as `AOC_RUSTC_VERSION`, which is stored alongside timings.
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Names of the parts, used in the names of generated tests.
+const PART_NAMES: [&str; 2] = ["part_one", "part_two"];
+
+fn main() -> io::Result<()> {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    let config_path = Path::new(&manifest_dir).join("aoc.toml");
+
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+    println!("cargo:rerun-if-changed={}", config_path.display());
+
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".into());
+    if let Ok(output) = Command::new(rustc).arg("-V").output() {
+        let version = String::from_utf8_lossy(&output.stdout);
+        println!("cargo:rustc-env=AOC_RUSTC_VERSION={}", version.trim());
+    }
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let data_dir = Path::new(&manifest_dir).join(read_data_dir(&config_path));
+
+    let mut puzzles: Vec<(String, String)> = vec![];
+
+    if bin_dir.exists() {
+        for entry in fs::read_dir(&bin_dir)? {
+            let path = entry?.path();
+
+            let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+
+            let Some((year, day)) = parse_bin_name(stem) else {
+                continue;
+            };
+
+            if path.extension().is_none_or(|ext| ext != "rs") {
+                continue;
+            }
+
+            // skip binaries that don't go through the template, they can't be registered.
+            if !fs::read_to_string(&path)?.contains("solution!(") {
+                continue;
+            }
+
+            write_example_tests(&data_dir, &out_dir, year, day)?;
+            puzzles.push((format!("puzzle_{year}_{day}"), path.display().to_string()));
+        }
+    }
+
+    puzzles.sort_unstable();
+
+    let mut registry = String::from("// @generated by build.rs\n\n");
+
+    for (module, path) in &puzzles {
+        registry.push_str(&format!(
+            "#[allow(dead_code, unused_imports, unused_variables)]\n#[path = {path:?}]\nmod {module};\n\n"
+        ));
+    }
+
+    registry.push_str("const SOLUTIONS: &[Solution] = &[\n");
+    for (module, _) in &puzzles {
+        registry.push_str(&format!("    {module}::SOLUTION,\n"));
+    }
+    registry.push_str("];\n");
+
+    fs::write(out_dir.join("solutions.rs"), registry)
+}
+
+/// Reads the data root from `aoc.toml`, see `src/template/config.rs`. Defaults to `data`.
+fn read_data_dir(config_path: &Path) -> String {
+    let Ok(contents) = fs::read_to_string(config_path) else {
+        return "data".into();
+    };
+
+    // an invalid config is reported when it's loaded at runtime.
+    contents
+        .parse::<toml::Table>()
+        .ok()
+        .and_then(|table| table.get("data_dir")?.as_str().map(Into::into))
+        .unwrap_or_else(|| "data".into())
+}
+
+/// Splits a binary name like `2024-01` into its year and day.
+/// Returns [`None`] for binaries that are not named after a puzzle.
+fn parse_bin_name(stem: &str) -> Option<(&str, &str)> {
+    let (year, day) = stem.split_once('-')?;
+
+    let is_digits = |s: &str, len: usize| s.len() == len && s.chars().all(|c| c.is_ascii_digit());
+    if !is_digits(year, 4) || !is_digits(day, 2) {
+        return None;
+    }
+
+    Some((year, day))
+}
+
+/// Generates a test per example file and declared answer of a puzzle, included by the `solution!` macro.
+///
+/// Answers are declared in a sidecar file next to the example, e.g. `data/2024/examples/01.answers` for `01.txt`
+/// and `01-2.answers` for `01-2.txt`. Each line holds the answer of a part, an empty answer expects `None`:
+///
+/// ```text
+/// part_1: 11
+/// part_2:
+/// ```
+fn write_example_tests(data_dir: &Path, out_dir: &Path, year: &str, day: &str) -> io::Result<()> {
+    let examples_dir = data_dir.join(year).join("examples");
+
+    let mut tests = String::from("// @generated by build.rs\n");
+
+    if examples_dir.exists() {
+        println!("cargo:rerun-if-changed={}", examples_dir.display());
+
+        let mut sidecars = vec![];
+        for entry in fs::read_dir(&examples_dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "answers") {
+                sidecars.push(path);
+            }
+        }
+        sidecars.sort_unstable();
+
+        for path in sidecars {
+            let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+
+            let example = match stem.split_once('-') {
+                Some((example_day, n)) if example_day == day => match n.parse::<u8>() {
+                    Ok(n) => Some(n),
+                    Err(_) => continue,
+                },
+                None if stem == day => None,
+                _ => continue,
+            };
+
+            if !path.with_extension("txt").exists() {
+                println!(
+                    "cargo:warning=answers {} have no example file, skipping.",
+                    path.display()
+                );
+                continue;
+            }
+
+            for (part, expected) in parse_answers(&path)? {
+                let name = match example {
+                    Some(n) => format!("{}_example_{n}", PART_NAMES[usize::from(part) - 1]),
+                    None => PART_NAMES[usize::from(part) - 1].into(),
+                };
+
+                tests.push_str(&format!(
+                    "\n#[test]\nfn {name}() {{\n    check({example:?}, {part}, {expected:?});\n}}\n"
+                ));
+            }
+        }
+    }
+
+    let tests_dir = out_dir.join("examples");
+    fs::create_dir_all(&tests_dir)?;
+
+    // the macro refers to the puzzle by the numbers passed to it, i.e. without leading zeros.
+    let day = day.trim_start_matches('0');
+    fs::write(tests_dir.join(format!("{year}-{day}.rs")), tests)
+}
+
+/// Reads the declared answers of an example file. Unknown lines are reported as warnings.
+fn parse_answers(path: &Path) -> io::Result<Vec<(u8, Option<String>)>> {
+    let mut answers = vec![];
+
+    for line in fs::read_to_string(path)?.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let part = match line.split_once(':') {
+            Some(("part_1", answer)) => Some((1, answer)),
+            Some(("part_2", answer)) => Some((2, answer)),
+            _ => None,
+        };
+
+        let Some((part, answer)) = part else {
+            println!(
+                "cargo:warning=unexpected line `{line}` in {}, expecting `part_1: <answer>` or `part_2: <answer>`.",
+                path.display()
+            );
+            continue;
+        };
+
+        let answer = answer.trim();
+        answers.push((part, (!answer.is_empty()).then(|| answer.to_string())));
+    }
+
+    Ok(answers)
+}
@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+
+use advent_of_code::input::Input;
+use advent_of_code::template::input_error::InputError;
+
+advent_of_code::solution!(2024, 1, try_parse = parse);
+
+type Lists = (Vec<isize>, Vec<isize>);
+
+pub fn part_one((list_one, list_two): &Lists) -> Option<usize> {
+    let mut list_one = list_one.clone();
+    let mut list_two = list_two.clone();
+
+    list_one.sort();
+    list_two.sort();
+
+    let total_distance: usize = list_one
+        .iter()
+        .enumerate()
+        .map(|(indx, el)| el.abs_diff(*list_two.get(indx).unwrap()))
+        .sum();
+
+    Some(total_distance)
+}
+
+pub fn part_two((list_one, list_two): &Lists) -> Option<isize> {
+    let list_two_freq = list_two
+        .iter()
+        .copied()
+        .fold(HashMap::new(), |mut acc, el| {
+            *acc.entry(el).or_insert(0) += 1;
+            acc
+        });
+
+    let similarity = list_one
+        .iter()
+        .map(|x| x * list_two_freq.get(x).unwrap_or(&0))
+        .sum();
+
+    Some(similarity)
+}
+
+fn parse(input: &str) -> Result<Lists, InputError> {
+    let pairs = Input::new(input).parse_lines(|line| line.ints_exact::<isize, 2>())?;
+
+    Ok(pairs.into_iter().map(|[left, right]| (left, right)).unzip())
+}
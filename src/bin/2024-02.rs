@@ -0,0 +1,46 @@
+use advent_of_code::input::Input;
+use advent_of_code::template::input_error::InputError;
+
+advent_of_code::solution!(2024, 2, try_parse = parse);
+
+type Reports = Vec<Vec<isize>>;
+
+pub fn part_one(reports: &Reports) -> Option<usize> {
+    Some(reports.iter().filter(|x| is_safe(x)).count())
+}
+
+pub fn part_two(reports: &Reports) -> Option<usize> {
+    Some(
+        reports
+            .iter()
+            .filter(|x| is_safe(x) || remove_any_is_safe(x))
+            .count(),
+    )
+}
+
+fn remove_any_is_safe(v: &[isize]) -> bool {
+    v.iter().enumerate().any(|(i, _)| {
+        let mut vector_without = v.to_vec();
+        vector_without.remove(i);
+
+        is_safe(&vector_without)
+    })
+}
+
+fn is_safe(v: &[isize]) -> bool {
+    let piece_wise_diffs = v.windows(2).map(|w| w[0] - w[1]).collect::<Vec<isize>>();
+
+    between_one_and_three(&piece_wise_diffs) && monotonic(&piece_wise_diffs)
+}
+
+fn between_one_and_three(v: &[isize]) -> bool {
+    v.iter().all(|&el| el.abs() >= 1 && el.abs() <= 3)
+}
+
+fn monotonic(v: &[isize]) -> bool {
+    v.iter().all(|&el| el >= 0) || v.iter().all(|&el| el <= 0)
+}
+
+fn parse(input: &str) -> Result<Reports, InputError> {
+    Input::new(input).parse_lines(|line| line.words())
+}
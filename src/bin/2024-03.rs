@@ -1,7 +1,7 @@
 use regex::Regex;
 use std::collections::BTreeMap;
 
-advent_of_code::solution!(3);
+advent_of_code::solution!(2024, 3);
 
 pub fn part_one(input: &str) -> Option<u32> {
     Some(get_products(input).iter().map(|(_, p)| p).sum())
@@ -46,24 +46,3 @@ fn product_from_mul(mul: &str) -> u32 {
         .map(|cap| cap[1].parse::<u32>().unwrap() * cap[2].parse::<u32>().unwrap())
         .sum()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
-        ));
-        assert_eq!(result, Some(161));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(48));
-    }
-}
@@ -0,0 +1,59 @@
+use advent_of_code::grid::{Grid, DELTAS_8};
+use advent_of_code::template::input_error::InputError;
+
+advent_of_code::solution!(2024, 4, try_parse = parse);
+
+pub fn part_one(grid: &Grid<char>) -> Option<usize> {
+    let word = vec!['X', 'M', 'A', 'S'];
+
+    let instances = find_instances(grid, &word);
+
+    Some(instances)
+}
+
+pub fn part_two(grid: &Grid<char>) -> Option<usize> {
+    let x_mas_count = grid
+        .find_all(|ch| *ch == 'A')
+        .filter(|&pos| {
+            // grab corners and verify mmss in some order
+            let is_mas = |a, b| {
+                let corners = (grid.offset(pos, a), grid.offset(pos, b));
+                match corners {
+                    (Some(a), Some(b)) => matches!((grid[a], grid[b]), ('M', 'S') | ('S', 'M')),
+                    // only As just off the edge of the grid are possible
+                    _ => false,
+                }
+            };
+
+            is_mas((-1, -1), (1, 1)) && is_mas((1, -1), (-1, 1))
+        })
+        .count();
+
+    Some(x_mas_count)
+}
+
+fn find_instances(grid: &Grid<char>, word: &[char]) -> usize {
+    grid.positions()
+        .map(|pos| {
+            DELTAS_8
+                .iter()
+                .filter(|&&direction| word_found_at(grid, pos, direction, word))
+                .count()
+        })
+        .sum()
+}
+
+fn word_found_at(
+    grid: &Grid<char>,
+    starts_from: (usize, usize),
+    direction: (isize, isize),
+    word: &[char],
+) -> bool {
+    let letters = grid.ray(starts_from, direction).map(|pos| grid[pos]);
+
+    letters.take(word.len()).eq(word.iter().copied())
+}
+
+fn parse(input: &str) -> Result<Grid<char>, InputError> {
+    Grid::parse(input, |ch| ch)
+}
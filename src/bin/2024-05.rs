@@ -0,0 +1,58 @@
+use advent_of_code::input::Input;
+use advent_of_code::template::input_error::InputError;
+use std::collections::HashMap;
+
+type Orderings = HashMap<(u32, u32), (u32, u32)>;
+
+advent_of_code::solution!(2024, 5, try_parse = parse, solve = solve);
+
+/// Sums the middle pages of the correctly ordered updates, and of the incorrectly ordered ones after sorting them.
+pub fn solve((orderings, updates): &(Orderings, Vec<Vec<u32>>)) -> (Option<u32>, Option<u32>) {
+    let mut correctly_ordered = 0;
+    let mut incorrectly_ordered = 0;
+    for update in updates {
+        let mut sorted_update = update.clone();
+
+        sorted_update.sort_by(|&a, &b| {
+            if let Some(order) = orderings.get(&(a.min(b), a.max(b))) {
+                if order.1 == a {
+                    std::cmp::Ordering::Greater
+                } else {
+                    std::cmp::Ordering::Less
+                }
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        });
+
+        let to_add = sorted_update.get(sorted_update.len() / 2).unwrap();
+
+        if &sorted_update != update {
+            incorrectly_ordered += to_add
+        } else {
+            correctly_ordered += to_add
+        }
+    }
+
+    (Some(correctly_ordered), Some(incorrectly_ordered))
+}
+
+fn parse(input: &str) -> Result<(Orderings, Vec<Vec<u32>>), InputError> {
+    let input = Input::new(input);
+    let [ordering_lines, update_lines] = input.split_sections()?;
+
+    let orderings = ordering_lines
+        .parse_lines(|l| {
+            let [first_int, last_int] = l.ints_exact::<u32, 2>()?;
+
+            Ok((
+                (first_int.min(last_int), first_int.max(last_int)), // key
+                (first_int, last_int),                              // value
+            ))
+        })?
+        .into_iter()
+        .collect::<HashMap<(u32, u32), (u32, u32)>>();
+    let updates = update_lines.parse_lines(|l| l.uints())?;
+
+    Ok((orderings, updates))
+}
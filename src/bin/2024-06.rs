@@ -0,0 +1,110 @@
+use advent_of_code::grid::Grid;
+use advent_of_code::pos::Direction;
+use advent_of_code::template::input_error::InputError;
+use itertools::Itertools;
+use std::collections::HashSet;
+
+advent_of_code::solution!(2024, 6, try_parse = parse);
+
+type Point = (usize, usize);
+type Lab = (Guard, Grid<bool>);
+
+#[derive(Clone, Hash, Eq, PartialEq)]
+pub struct Guard {
+    position: Point,
+    direction: Direction,
+}
+
+impl Guard {
+    /// The guard one step ahead, [`None`] if that step leaves the lab.
+    fn move_one(&self, walls: &Grid<bool>) -> Option<Guard> {
+        Some(Guard {
+            position: walls.offset(self.position, self.direction.delta())?,
+            direction: self.direction,
+        })
+    }
+
+    fn turn_right(&self) -> Guard {
+        Guard {
+            position: self.position,
+            direction: self.direction.turn_right(),
+        }
+    }
+}
+
+pub fn part_one((guard, walls): &Lab) -> Option<usize> {
+    let (path, _) = move_guard(guard, walls, &None);
+
+    Some(path.into_iter().map(|a| a.position).unique().count())
+}
+
+pub fn part_two((guard, walls): &Lab) -> Option<usize> {
+    let (path, _) = move_guard(guard, walls, &None);
+
+    Some(
+        path.into_iter()
+            .unique_by(|a| a.position)
+            .tuple_windows()
+            .filter_map(|(new_guard, obstr)| {
+                let (_, does_loop) = move_guard(&new_guard, walls, &Some(obstr.position));
+
+                if does_loop {
+                    Some(obstr.position)
+                } else {
+                    None
+                }
+            })
+            .count(),
+    )
+}
+
+fn move_guard(
+    guard: &Guard,
+    walls: &Grid<bool>,
+    obstruction: &Option<Point>,
+) -> (Vec<Guard>, bool) {
+    let mut guard = guard.to_owned();
+    let mut states_seen: HashSet<Guard> = HashSet::new();
+    let mut path = Vec::with_capacity(100);
+
+    loop {
+        path.push(guard.to_owned());
+
+        let Some(next) = guard.move_one(walls) else {
+            // the guard left the lab
+            return (path, false);
+        };
+
+        guard = if walls[next.position] || obstruction.eq(&Some(next.position)) {
+            if states_seen.contains(&guard) {
+                return (path, true);
+            }
+
+            // we would hit a wall, instead let's turn 90º clockwise
+            states_seen.insert(guard.to_owned());
+            guard.turn_right()
+        } else {
+            // no wall, move!
+            next
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<Lab, InputError> {
+    let lab = Grid::parse(input, |ch| ch)?;
+
+    let position = lab.find(|ch| *ch == '^').ok_or_else(|| {
+        InputError::on_line(
+            0,
+            input.lines().next().unwrap_or(""),
+            "the guard is not in the lab",
+        )
+    })?;
+
+    let guard = Guard {
+        position,
+        direction: Direction::North,
+    };
+
+    Ok((guard, lab.map(|ch| *ch == '#')))
+}
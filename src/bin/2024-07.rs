@@ -1,6 +1,6 @@
 use std::cmp::PartialEq;
 
-advent_of_code::solution!(7);
+advent_of_code::solution!(2024, 7);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let operations = vec![Operation::Add, Operation::Mul];
@@ -14,7 +14,7 @@ pub fn part_two(input: &str) -> Option<usize> {
     find_test_sum(input, &operations)
 }
 
-fn find_test_sum(input: &str, operations: &Vec<Operation>) -> Option<usize> {
+fn find_test_sum(input: &str, operations: &[Operation]) -> Option<usize> {
     let equations = parse(input);
     let valid = equations
         .into_iter()
@@ -87,20 +87,3 @@ fn parse(input: &str) -> Vec<(usize, Vec<usize>)> {
         })
         .collect()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3749));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(11387));
-    }
-}
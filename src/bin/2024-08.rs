@@ -4,36 +4,31 @@ use num::Integer;
 use std::collections::{HashMap, HashSet};
 use std::ops::{Add, Div, Mul};
 
-advent_of_code::solution!(8);
+advent_of_code::solution!(2024, 8, parse = parse);
 
 type Point = Point2D<isize, isize>;
 type Vector = Vector2D<isize, isize>;
+type Antennas = (HashMap<char, Vec<Point>>, Point);
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let (antennas, bounds) = parse(input);
-
+pub fn part_one((antennas, bounds): &Antennas) -> Option<usize> {
     let all_anti_nodes = antennas
         .values()
-        .map(|antennas| find_anti_nodes(&antennas, &bounds))
-        .flatten()
+        .flat_map(|antennas| find_anti_nodes(antennas, bounds))
         .collect::<HashSet<Point>>();
 
     Some(all_anti_nodes.len())
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let (antennas, bounds) = parse(input);
-
+pub fn part_two((antennas, bounds): &Antennas) -> Option<usize> {
     let all_t_nodes = antennas
         .values()
-        .map(|antennas| find_t_nodes(&antennas, &bounds))
-        .flatten()
+        .flat_map(|antennas| find_t_nodes(antennas, bounds))
         .collect::<HashSet<Point>>();
 
     Some(all_t_nodes.len())
 }
 
-fn find_anti_nodes(antennas: &Vec<Point>, bounds: &Point) -> HashSet<Point> {
+fn find_anti_nodes(antennas: &[Point], bounds: &Point) -> HashSet<Point> {
     let product = iproduct!(antennas.iter(), antennas.iter());
 
     product
@@ -47,7 +42,7 @@ fn find_anti_nodes(antennas: &Vec<Point>, bounds: &Point) -> HashSet<Point> {
         .collect::<HashSet<Point>>()
 }
 
-fn find_t_nodes(antennas: &Vec<Point>, bounds: &Point) -> HashSet<Point> {
+fn find_t_nodes(antennas: &[Point], bounds: &Point) -> HashSet<Point> {
     let product = iproduct!(antennas.iter(), antennas.iter());
 
     product
@@ -57,7 +52,7 @@ fn find_t_nodes(antennas: &Vec<Point>, bounds: &Point) -> HashSet<Point> {
             let gcd = d.x.gcd(&d.y);
             let d_reduced = d.div(gcd);
 
-            ray_points(&a, &d_reduced, &bounds)
+            ray_points(&a, &d_reduced, bounds)
         })
         .collect::<HashSet<Point>>()
 }
@@ -80,9 +75,8 @@ fn ray_points(anchor: &Point, step: &Vector, bounds: &Point) -> Vec<Point> {
     let start_point = anchor.add(step.mul(-num_points));
 
     (0..=num_points * 2)
-        .into_iter()
         .map(|i| start_point.add(step.mul(i)))
-        .filter(|x| within_bounds(&x, &bounds))
+        .filter(|x| within_bounds(x, bounds))
         .collect()
 }
 
@@ -90,13 +84,13 @@ fn within_bounds(point: &Point, bounds: &Point) -> bool {
     point.x <= bounds.x && point.y <= bounds.y && point.x >= 0 && point.y >= 0
 }
 
-fn parse(input: &str) -> (HashMap<char, Vec<Point>>, Point) {
+fn parse(input: &str) -> Antennas {
     let mut bounds = point2(0, 0);
 
     let antennas = input
         .lines()
         .enumerate()
-        .map(|(r, row)| {
+        .flat_map(|(r, row)| {
             row.chars()
                 .enumerate()
                 .filter_map(|(c, ch)| {
@@ -111,7 +105,6 @@ fn parse(input: &str) -> (HashMap<char, Vec<Point>>, Point) {
                 })
                 .collect::<Vec<(Point, char)>>()
         })
-        .flatten()
         .fold(HashMap::new(), |mut acc, antenna| {
             let antennas = acc.entry(antenna.1).or_insert(Vec::new());
             antennas.push(antenna.0);
@@ -121,20 +114,3 @@ fn parse(input: &str) -> (HashMap<char, Vec<Point>>, Point) {
 
     (antennas, bounds)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(14));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(34));
-    }
-}
@@ -1,4 +1,4 @@
-advent_of_code::solution!(9);
+advent_of_code::solution!(2024, 9);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let mut output = create_drive(input);
@@ -138,7 +138,7 @@ fn build_free_spaces(drive: &[i32]) -> Vec<(usize, usize)> {
     spaces
 }
 
-fn calculate_checksum(output: &Vec<i32>) -> usize {
+fn calculate_checksum(output: &[i32]) -> usize {
     output
         .iter()
         .enumerate()
@@ -153,7 +153,7 @@ fn calculate_checksum(output: &Vec<i32>) -> usize {
 }
 
 fn empty_space(
-    free_spaces: &Vec<(usize, usize)>,
+    free_spaces: &[(usize, usize)],
     up_to: usize,
     desired_length: usize,
 ) -> Option<usize> {
@@ -175,20 +175,3 @@ fn empty_space(
             }
         })
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1928));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2858));
-    }
-}
@@ -0,0 +1,60 @@
+use advent_of_code::grid::Grid;
+use advent_of_code::template::input_error::InputError;
+use itertools::Itertools;
+
+advent_of_code::solution!(2024, 10);
+
+pub fn part_one(input: &str) -> Result<u32, InputError> {
+    let height_map = parse_map(input)?;
+
+    let trail_head_total = height_map
+        .find_all(|height| *height == 0)
+        .map(|pos| trail_head_score(pos, &height_map, true))
+        .sum::<u32>();
+
+    Ok(trail_head_total)
+}
+
+pub fn part_two(input: &str) -> Result<u32, InputError> {
+    let height_map = parse_map(input)?;
+
+    let trail_head_total = height_map
+        .find_all(|height| *height == 0)
+        .map(|pos| trail_head_score(pos, &height_map, false))
+        .sum::<u32>();
+
+    Ok(trail_head_total)
+}
+
+fn trail_head_score(pos: (usize, usize), height_map: &Grid<u8>, unique: bool) -> u32 {
+    let mut paths = vec![pos];
+
+    let mut value = 0;
+    while !paths.is_empty() && value != 9 {
+        paths = paths
+            .into_iter()
+            .flat_map(|node| get_successors(node, height_map))
+            .collect();
+
+        if unique {
+            paths = paths.into_iter().unique().collect()
+        }
+
+        value += 1;
+    }
+
+    paths.len() as u32
+}
+
+fn get_successors(pos: (usize, usize), height_map: &Grid<u8>) -> Vec<(usize, usize)> {
+    let pos_val = height_map[pos];
+
+    height_map
+        .neighbours_4(pos)
+        .filter(|&next| height_map[next] == pos_val + 1)
+        .collect()
+}
+
+fn parse_map(input: &str) -> Result<Grid<u8>, InputError> {
+    Grid::try_parse(input, |ch| ch.to_digit(10).map(|height| height as u8))
+}
@@ -1,13 +1,14 @@
-use cached::proc_macro::cached;
+use std::collections::HashMap;
 
-advent_of_code::solution!(11);
+advent_of_code::solution!(2024, 11);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let numbers = parse(input);
+    let mut memo = HashMap::new();
 
     let num_stones = numbers
         .into_iter()
-        .map(|number| stones_after_blinks(number, 25))
+        .map(|number| stones_after_blinks(number, 25, &mut memo))
         .sum::<usize>();
 
     Some(num_stones)
@@ -15,22 +16,29 @@ pub fn part_one(input: &str) -> Option<usize> {
 
 pub fn part_two(input: &str) -> Option<usize> {
     let numbers = parse(input);
+    let mut memo = HashMap::new();
     let num_stones = numbers
         .into_iter()
-        .map(|number| stones_after_blinks(number, 75))
+        .map(|number| stones_after_blinks(number, 75, &mut memo))
         .sum::<usize>();
 
     Some(num_stones)
 }
 
-#[cached]
-fn stones_after_blinks(value: usize, blinks: usize) -> usize {
+fn stones_after_blinks(
+    value: usize,
+    blinks: usize,
+    memo: &mut HashMap<(usize, usize), usize>,
+) -> usize {
     if blinks == 0 {
         return 1;
     }
+    if let Some(&count) = memo.get(&(value, blinks)) {
+        return count;
+    }
 
-    if value == 0 {
-        stones_after_blinks(1, blinks - 1)
+    let count = if value == 0 {
+        stones_after_blinks(1, blinks - 1, memo)
     } else if value.ilog10() % 2 == 1 {
         // even number of digits
         let divisor = 10_i32.pow(1 + value.ilog10() / 2) as usize;
@@ -39,13 +47,16 @@ fn stones_after_blinks(value: usize, blinks: usize) -> usize {
         let left = value / divisor;
         let right = value - (left * divisor);
 
-        let left_count = stones_after_blinks(left, blinks - 1);
-        let right_count = stones_after_blinks(right, blinks - 1);
+        let left_count = stones_after_blinks(left, blinks - 1, memo);
+        let right_count = stones_after_blinks(right, blinks - 1, memo);
 
         left_count + right_count
     } else {
-        stones_after_blinks(value * 2024, blinks - 1)
-    }
+        stones_after_blinks(value * 2024, blinks - 1, memo)
+    };
+
+    memo.insert((value, blinks), count);
+    count
 }
 
 fn parse(input: &str) -> Vec<usize> {
@@ -57,20 +68,3 @@ fn parse(input: &str) -> Vec<usize> {
         .map(|num| num.parse::<usize>().expect("Not a number!"))
         .collect::<Vec<usize>>()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(55312));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(65601038650482));
-    }
-}
@@ -0,0 +1,135 @@
+use advent_of_code::grid::Grid;
+use advent_of_code::template::input_error::InputError;
+use std::collections::HashSet;
+
+advent_of_code::solution!(2024, 12, try_parse = parse);
+
+type Garden = Grid<char>;
+type Region = (HashSet<(usize, usize)>, i32, char);
+
+pub fn part_one(garden: &Garden) -> Option<i32> {
+    let garden_score = get_regions(garden)
+        .iter()
+        .map(|(region, perimeter, _)| region.len() as i32 * perimeter)
+        .sum();
+
+    Some(garden_score)
+}
+
+pub fn part_two(garden: &Garden) -> Option<i32> {
+    let garden_score = get_regions(garden)
+        .iter()
+        .map(|(region, _, _)| {
+            let num_sides = get_sides(region);
+
+            region.len() as i32 * num_sides
+        })
+        .sum();
+
+    Some(garden_score)
+}
+
+fn get_regions(garden: &Garden) -> Vec<Region> {
+    let mut visited = HashSet::new();
+    let mut regions = vec![];
+
+    for (plot, ch) in garden.iter() {
+        if visited.contains(&plot) {
+            continue;
+        }
+
+        let (region, perimeter) = get_region(plot, garden);
+        regions.push((region.clone(), perimeter, *ch));
+
+        for plot in region {
+            visited.insert(plot);
+        }
+    }
+
+    regions
+}
+
+fn get_sides(region: &HashSet<(usize, usize)>) -> i32 {
+    let max_bounds = region
+        .iter()
+        .copied()
+        .reduce(|a, b| (a.0.max(b.0), a.1.max(b.1)))
+        .unwrap();
+
+    let min_bounds = region
+        .iter()
+        .copied()
+        .reduce(|a, b| (a.0.min(b.0), a.1.min(b.1)))
+        .unwrap();
+
+    let mut horz_sides = 0;
+    for r in min_bounds.0..=max_bounds.0 + 1 {
+        let mut previous_up_exists = false;
+        let mut previous_down_exists = false;
+
+        for c in min_bounds.1..=max_bounds.1 {
+            // is there a border here?
+            let up_exists = r > 0 && region.contains(&(r - 1, c));
+            let down_exists = region.contains(&(r, c));
+            let edge_exists = up_exists != down_exists;
+            let new_edge = previous_up_exists != up_exists || previous_down_exists != down_exists;
+
+            if new_edge && edge_exists {
+                // we've just entered a new edge! Add it!
+                horz_sides += 1;
+            }
+
+            previous_up_exists = up_exists;
+            previous_down_exists = down_exists;
+        }
+    }
+
+    // number of horizontal sides == number of vertical sides, so double this figure!
+    horz_sides * 2
+}
+
+fn get_region(initial: (usize, usize), garden: &Garden) -> (HashSet<(usize, usize)>, i32) {
+    let mut region = HashSet::new();
+    region.insert(initial);
+    let mut wave = vec![initial];
+
+    let mut perimeter = 0;
+    while !wave.is_empty() {
+        let mut new_wave = vec![];
+
+        for plot in wave {
+            let neighbours = get_matching_neighbours(plot, garden);
+
+            // the perimeter of a given plot is the number of neighbouring elements that _don't_ match its type
+            perimeter += 4 - neighbours.len() as i32;
+
+            for neighbour in neighbours {
+                // if the neighbour is already in our region, ignore it and carry on
+                if region.contains(&neighbour) {
+                    continue;
+                }
+
+                // we've not seen this plot yet, add it to the next wave and region
+                new_wave.push(neighbour);
+                region.insert(neighbour);
+            }
+        }
+
+        wave = new_wave;
+    }
+
+    (region, perimeter)
+}
+
+fn parse(input: &str) -> Result<Garden, InputError> {
+    Grid::parse(input, |ch| ch)
+}
+
+fn get_matching_neighbours(of: (usize, usize), garden: &Garden) -> Vec<(usize, usize)> {
+    let plot_type = garden[of];
+
+    garden
+        .neighbours_4(of)
+        .filter(|&pos| garden[pos] == plot_type)
+        .collect()
+}
@@ -0,0 +1,79 @@
+use advent_of_code::input::{Input, Line};
+use advent_of_code::template::input_error::InputError;
+use euclid::{point2, Point2D};
+use std::ops::Mul;
+
+advent_of_code::solution!(2024, 13);
+
+#[derive(Debug)]
+struct Game {
+    a_delta: Point2D<i64, i64>,
+    b_delta: Point2D<i64, i64>,
+    target: Point2D<i64, i64>,
+}
+
+const COSTS: (i64, i64) = (3, 1);
+
+pub fn part_one(input: &str) -> Result<i64, InputError> {
+    let games = parse(input)?;
+    let total_cost = games.iter().filter_map(min_cost).sum::<i64>();
+
+    Ok(total_cost)
+}
+
+pub fn part_two(input: &str) -> Result<i64, InputError> {
+    const CONVERSION_DELTA: i64 = 10000000000000;
+
+    let games = parse(input)?;
+    let total_cost = games
+        .into_iter()
+        .map(|g| Game {
+            target: point2(g.target.x + CONVERSION_DELTA, g.target.y + CONVERSION_DELTA),
+            ..g
+        })
+        .filter_map(|g| min_cost(&g))
+        .sum::<i64>();
+
+    Ok(total_cost)
+}
+
+fn min_cost(game: &Game) -> Option<i64> {
+    // TODO, I'm not sure this is necessarily valid for cases where a_delta and b_delta are not co-prime vectors
+    // luckily, that appears to not be the case here...
+    let b_pushes = (game.a_delta.y * game.target.x - game.a_delta.x * game.target.y)
+        / (game.a_delta.y * game.b_delta.x - game.a_delta.x * game.b_delta.y);
+    let a_pushes = (game.target.y - b_pushes * game.b_delta.y) / game.a_delta.y;
+
+    let total = game.a_delta.mul(a_pushes) + game.b_delta.mul(b_pushes).to_vector();
+
+    if total == game.target {
+        Some(a_pushes * COSTS.0 + b_pushes * COSTS.1)
+    } else {
+        None
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Game>, InputError> {
+    let point = |line: Line| {
+        let [x, y] = line.ints_exact()?;
+        Ok(point2(x, y))
+    };
+
+    Input::new(input)
+        .sections()
+        .iter()
+        .map(|game| {
+            let lines = game.lines().collect::<Vec<Line>>();
+
+            let [a, b, prize] = lines[..] else {
+                return Err(lines[0].error("expected two buttons and a prize"));
+            };
+
+            Ok(Game {
+                a_delta: point(a)?,
+                b_delta: point(b)?,
+                target: point(prize)?,
+            })
+        })
+        .collect()
+}
@@ -1,10 +1,21 @@
+use advent_of_code::input::Input;
+use advent_of_code::template::input_error::InputError;
 use euclid::default::{Point2D, Vector2D};
 use euclid::{point2, vec2};
 use std::collections::HashMap;
 
-advent_of_code::solution!(14);
+advent_of_code::solution!(2024, 14, try_parse = parse, params = Params);
 
-struct Robot {
+advent_of_code::params! {
+    pub struct Params {
+        width: isize = 101,
+        height: isize = 103,
+    }
+}
+
+type Robots = Vec<Robot>;
+
+pub struct Robot {
     position: Point2D<isize>,
     velocity: Vector2D<isize>,
 }
@@ -30,10 +41,10 @@ impl Robot {
 
 const SECONDS: isize = 100;
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let (robots, bounds) = parse(input);
+pub fn part_one(robots: &Robots, params: &Params) -> Option<u32> {
+    let bounds: Point2D<isize> = point2(params.width, params.height);
 
-    let new_robots = robots
+    let new_robots: Vec<Robot> = robots
         .iter()
         .map(|robot| move_robot(robot, SECONDS, &bounds))
         .collect();
@@ -43,8 +54,8 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(safety_score)
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let (robots, bounds) = parse(input);
+pub fn part_two(robots: &Robots, params: &Params) -> Option<usize> {
+    let bounds: Point2D<isize> = point2(params.width, params.height);
 
     let x_offset = find_dim_frequency(
         robots
@@ -73,7 +84,9 @@ fn frequency_alignment(
     (0..freq_b).find_map(|bx| {
         let alignment = offset_a + (freq_a * bx);
 
-        if (alignment - offset_a) % freq_a == 0 && (alignment - offset_b) % freq_b == 0 {
+        if (alignment - offset_a).is_multiple_of(freq_a)
+            && (alignment - offset_b).is_multiple_of(freq_b)
+        {
             Some(alignment)
         } else {
             None
@@ -106,7 +119,7 @@ fn find_dim_frequency(particles: Vec<(isize, isize)>, bound: isize) -> usize {
     best_grouping.0 as usize
 }
 
-fn get_quadrants(robots: &Vec<Robot>, bounds: &Point2D<isize>) -> Vec<u32> {
+fn get_quadrants(robots: &[Robot], bounds: &Point2D<isize>) -> Vec<u32> {
     robots
         .iter()
         .fold([0, 0, 0, 0, 0], |mut acc: [u32; 5], robot| {
@@ -129,36 +142,18 @@ fn move_robot(robot: &Robot, seconds: isize, bounds: &Point2D<isize>) -> Robot {
     }
 }
 
-fn parse(input: &str) -> (Vec<Robot>, Point2D<isize>) {
-    let robots: Vec<Robot> = input
-        .lines()
-        .map(|l| {
-            let (pos, v) = l.split_once(' ').expect("Invalid line");
+fn parse(input: &str) -> Result<Robots, InputError> {
+    Input::new(input).parse_lines(|l| {
+        let [x, y, dx, dy] = l.ints_exact()?;
 
-            Robot {
-                position: numbers_to_vector(pos).to_point(),
-                velocity: numbers_to_vector(v),
-            }
+        Ok(Robot {
+            position: point2(x, y),
+            velocity: vec2(dx, dy),
         })
-        .collect();
-
-    // differentiate between the example and input
-    let bounds = if robots.len() == 12 {
-        point2(11, 7)
-    } else {
-        point2(101, 103)
-    };
-
-    (robots, bounds)
-}
-
-fn numbers_to_vector(input: &str) -> Vector2D<isize> {
-    let (left, right) = input[2..].split_once(',').unwrap();
-
-    vec2(left.parse().unwrap(), right.parse().unwrap())
+    })
 }
 
-fn _print(robots: &Vec<Robot>, bounds: &Point2D<isize>) {
+fn _print(robots: &[Robot], bounds: &Point2D<isize>) {
     let positions = robots.iter().fold(HashMap::new(), |mut acc, robot| {
         let key = (robot.position.x, robot.position.y);
 
@@ -179,20 +174,3 @@ fn _print(robots: &Vec<Robot>, bounds: &Point2D<isize>) {
         println!();
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(12));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(31));
-    }
-}
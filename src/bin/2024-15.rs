@@ -1,12 +1,12 @@
-use euclid::{point2, vec2, Point2D, Vector2D};
+use advent_of_code::pos::{Direction, Pos, PosExt, Vector};
+use euclid::point2;
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
 use std::ops::Add;
 
-advent_of_code::solution!(15);
+advent_of_code::solution!(2024, 15);
 
-type Point = Point2D<isize, isize>;
-type Vector = Vector2D<isize, isize>;
+type Point = Pos;
 
 pub fn part_one(input: &str) -> Option<isize> {
     let (robot, walls, boxes, directions) = parse(input);
@@ -157,11 +157,10 @@ fn parse(input: &str) -> (Point, HashSet<Point>, HashMap<Point, Point>, Vec<Vect
     let mut walls = HashSet::new();
     let mut boxes = HashMap::new();
     let mut robot = None;
-    let right_vector = vec2(0, 1);
 
     for (r, row) in map.lines().enumerate() {
         for (c, ch) in row.chars().enumerate() {
-            let point = point2(r as isize, c as isize);
+            let point = Point::from_grid((r, c));
 
             match ch {
                 '#' => {
@@ -171,7 +170,7 @@ fn parse(input: &str) -> (Point, HashSet<Point>, HashMap<Point, Point>, Vec<Vect
                     boxes.insert(point, point);
                 }
                 '[' => {
-                    let right_point = point.add(right_vector);
+                    let right_point = point.step(Direction::East);
 
                     boxes.insert(point, right_point);
                     boxes.insert(right_point, point);
@@ -187,14 +186,9 @@ fn parse(input: &str) -> (Point, HashSet<Point>, HashMap<Point, Point>, Vec<Vect
     let directions = direction_string
         .lines()
         .flat_map(|l| {
-            l.chars().map(|ch| match ch {
-                '>' => vec2(0, 1),
-                '<' => vec2(0, -1),
-                '^' => vec2(-1, 0),
-                'v' => vec2(1, 0),
-                _ => {
-                    panic!("Unexpected character '{ch}'!");
-                }
+            l.chars().map(|ch| match Direction::try_from(ch) {
+                Ok(direction) => direction.vector(),
+                Err(error) => panic!("{error}"),
             })
         })
         .collect::<Vec<Vector>>();
@@ -231,20 +225,3 @@ fn _print_map(robot: &Point, boxes: &HashMap<Point, Point>, walls: &HashSet<Poin
     // flush the stdout buffer
     println!("\n\n\n\n");
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(10092));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(9021));
-    }
-}
@@ -0,0 +1,105 @@
+extern crate core;
+
+use advent_of_code::pos::{Direction, Pos, PosExt};
+use advent_of_code::search::{dijkstra, ShortestPaths};
+use itertools::Itertools;
+use std::collections::HashSet;
+
+advent_of_code::solution!(2024, 16, parse = parse);
+
+type Point = Pos;
+type Maze = (Point, Point, HashSet<Point>);
+
+#[derive(Hash, PartialEq, Eq, Clone, Debug, Copy)]
+struct State {
+    position: Point,
+    direction: Direction,
+}
+
+pub fn part_one((start, end, map): &Maze) -> Option<usize> {
+    find_optimal_paths(*start, *end, map).goal_distance()
+}
+
+pub fn part_two((start, end, map): &Maze) -> Option<usize> {
+    let paths = find_optimal_paths(*start, *end, map);
+
+    Some(
+        paths
+            .nodes_on_shortest_paths()
+            .iter()
+            .map(|state| state.position)
+            .unique()
+            .count(),
+    )
+}
+
+fn find_optimal_paths(
+    start: Point,
+    end: Point,
+    map: &HashSet<Point>,
+) -> ShortestPaths<State, usize> {
+    let from = State {
+        position: start,
+        direction: Direction::East,
+    };
+
+    dijkstra(
+        from,
+        |particle| get_neighbours(particle, map),
+        |particle| particle.position == end,
+    )
+}
+
+fn get_neighbours(particle: &State, map: &HashSet<Point>) -> Vec<(State, usize)> {
+    // move forward or turn 90º either direction
+    let forward = particle.direction;
+
+    [
+        (forward, 1),
+        (forward.turn_left(), 1001),
+        (forward.turn_right(), 1001),
+    ]
+    .into_iter()
+    .map(|(direction, cost)| {
+        let state = State {
+            position: particle.position.step(direction),
+            direction,
+        };
+
+        (state, cost)
+    })
+    .filter(|(p, _)| map.contains(&p.position))
+    .collect()
+}
+
+fn parse(input: &str) -> Maze {
+    let mut start = None;
+    let mut end = None;
+
+    let map = input
+        .lines()
+        .enumerate()
+        .flat_map(|(r, row)| {
+            row.chars()
+                .enumerate()
+                .filter(|(_, ch)| ch != &'#')
+                .map(|(c, ch)| {
+                    let point = Point::from_grid((r, c));
+                    match ch {
+                        'S' => {
+                            start = Some(point);
+                        }
+                        'E' => {
+                            end = Some(point);
+                        }
+                        _ => {}
+                    };
+
+                    point
+                })
+                .collect::<Vec<Point>>()
+        })
+        .collect::<HashSet<Point>>();
+
+    (start.unwrap(), end.unwrap(), map)
+}
@@ -1,7 +1,7 @@
 use itertools::Itertools;
 use std::ops::BitXor;
 
-advent_of_code::solution!(17);
+advent_of_code::solution!(2024, 17);
 
 const A: usize = 0;
 const B: usize = 1;
@@ -58,15 +58,11 @@ pub fn part_two(input: &str) -> Option<usize> {
             .collect()
     }
 
-    octal_paths.iter().min().map(octals_to_int)
+    octal_paths.iter().min().map(|octals| octals_to_int(octals))
 }
 
-fn find_valid_digits(
-    program: &Vec<(usize, usize)>,
-    octals: &Vec<usize>,
-    expected: usize,
-) -> Vec<usize> {
-    let mut octals = octals.clone();
+fn find_valid_digits(program: &[(usize, usize)], octals: &[usize], expected: usize) -> Vec<usize> {
+    let mut octals = octals.to_vec();
     octals.insert(0, 0);
 
     (0..8_usize)
@@ -83,7 +79,7 @@ fn find_valid_digits(
         .collect()
 }
 
-fn octals_to_int(octals: &Vec<usize>) -> usize {
+fn octals_to_int(octals: &[usize]) -> usize {
     octals
         .iter()
         .enumerate()
@@ -91,8 +87,8 @@ fn octals_to_int(octals: &Vec<usize>) -> usize {
         .sum()
 }
 
-fn run_program(registers: &Vec<usize>, program: &Vec<(usize, usize)>) -> Vec<usize> {
-    let mut registers = registers.clone();
+fn run_program(registers: &[usize], program: &[(usize, usize)]) -> Vec<usize> {
+    let mut registers = registers.to_vec();
     let mut instruction_pointer = 0;
     let mut output = vec![];
 
@@ -134,7 +130,7 @@ fn run_program(registers: &Vec<usize>, program: &Vec<(usize, usize)>) -> Vec<usi
     output
 }
 
-fn adv(registers: &Vec<usize>, combo_operand: usize) -> usize {
+fn adv(registers: &[usize], combo_operand: usize) -> usize {
     registers[A] / 2_usize.pow(combo_operand as u32)
 }
 
@@ -158,23 +154,3 @@ fn parse(input: &str) -> (Vec<usize>, Vec<(usize, usize)>) {
 
     (registers, instructions)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-
-        assert_eq!(result, Some("4,6,3,5,6,3,5,2,1,0".to_owned()));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(117440));
-    }
-}
@@ -1,15 +1,21 @@
+use advent_of_code::search::bfs;
 use euclid::{point2, vec2, Point2D};
-use priority_queue::PriorityQueue;
-use std::cmp::Reverse;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 use std::ops::Add;
 
-advent_of_code::solution!(18);
+advent_of_code::solution!(2024, 18, params = Params);
+
+advent_of_code::params! {
+    pub struct Params {
+        size: isize = 70,
+        bytes: usize = 1024,
+    }
+}
 
 type Point = Point2D<isize, isize>;
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let (byte_points, bounds, take) = parse(input);
+pub fn part_one(input: &str, params: &Params) -> Option<usize> {
+    let (byte_points, bounds, take) = parse(input, params);
 
     min_path(
         point2(0, 0),
@@ -21,8 +27,8 @@ pub fn part_one(input: &str) -> Option<usize> {
     )
 }
 
-pub fn part_two(input: &str) -> Option<String> {
-    let (byte_points, bounds, take) = parse(input);
+pub fn part_two(input: &str, params: &Params) -> Option<String> {
+    let (byte_points, bounds, take) = parse(input, params);
 
     let mut range = take..byte_points.len();
     while !range.is_empty() {
@@ -51,27 +57,12 @@ pub fn part_two(input: &str) -> Option<String> {
 }
 
 fn min_path(start: Point, bounds: &Point, byte_points: &HashSet<Point>) -> Option<usize> {
-    let mut visited = HashMap::new();
-    let mut queue: PriorityQueue<Point, Reverse<usize>> = PriorityQueue::new();
-    queue.push(start, Reverse(0));
-
-    while let Some((element, Reverse(value))) = queue.pop() {
-        visited.insert(element, value);
-        if &element == bounds {
-            break;
-        }
-
-        let neighbours = neighbours(&element, bounds, byte_points);
-
-        neighbours
-            .into_iter()
-            .filter(|x| !visited.contains_key(x))
-            .for_each(|n| {
-                queue.push(n, Reverse(value + 1));
-            });
-    }
-
-    visited.get(bounds).map(|x| x.to_owned())
+    bfs(
+        start,
+        |point| neighbours(point, bounds, byte_points),
+        |point| point == bounds,
+    )
+    .goal_distance()
 }
 
 fn neighbours(point: &Point, bounds: &Point, byte_points: &HashSet<Point>) -> Vec<Point> {
@@ -87,14 +78,7 @@ fn neighbours(point: &Point, bounds: &Point, byte_points: &HashSet<Point>) -> Ve
     .collect()
 }
 
-fn parse(input: &str) -> (Vec<Point>, Point, usize) {
-    // differentiate between the example and the actual input
-    let (bounds, take) = if input.lines().count() == 25 {
-        (point2(6, 6), 12)
-    } else {
-        (point2(70, 70), 1024)
-    };
-
+fn parse(input: &str, params: &Params) -> (Vec<Point>, Point, usize) {
     let byte_points = input
         .lines()
         .map(|p| {
@@ -104,22 +88,5 @@ fn parse(input: &str) -> (Vec<Point>, Point, usize) {
         })
         .collect::<Vec<Point>>();
 
-    (byte_points, bounds, take)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(22));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some("6,1".to_string()));
-    }
+    (byte_points, point2(params.size, params.size), params.bytes)
 }
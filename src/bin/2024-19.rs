@@ -0,0 +1,100 @@
+use advent_of_code::input::Input;
+use advent_of_code::template::input_error::InputError;
+use std::collections::HashMap;
+
+advent_of_code::solution!(2024, 19);
+
+type Pattern = String;
+
+pub fn part_one(input: &str) -> Result<usize, InputError> {
+    let (types, wanted) = parse(input)?;
+    let mut memo = HashMap::new();
+
+    Ok(wanted
+        .into_iter()
+        .map(|p| num_combinations(p, &types, &mut memo) > 0)
+        .filter(|x| *x)
+        .count())
+}
+
+pub fn part_two(input: &str) -> Result<usize, InputError> {
+    let (types, wanted) = parse(input)?;
+    let mut memo = HashMap::new();
+
+    Ok(wanted
+        .into_iter()
+        .map(|p| num_combinations(p, &types, &mut memo))
+        .sum())
+}
+
+/// `memo` is only valid for one set of `from_prefixes`, so each input gets a fresh one.
+fn num_combinations(
+    pattern: Pattern,
+    from_prefixes: &Vec<Pattern>,
+    memo: &mut HashMap<Pattern, usize>,
+) -> usize {
+    if pattern.is_empty() {
+        return 1;
+    }
+    if let Some(&count) = memo.get(&pattern) {
+        return count;
+    }
+
+    let count = from_prefixes
+        .iter()
+        .map(|prefix| {
+            if pattern.starts_with(prefix) {
+                let remaining = pattern[prefix.len()..].to_owned();
+
+                num_combinations(remaining, from_prefixes, memo)
+            } else {
+                0
+            }
+        })
+        .sum();
+
+    memo.insert(pattern, count);
+    count
+}
+
+fn parse(input: &str) -> Result<(Vec<Pattern>, Vec<Pattern>), InputError> {
+    let input = Input::new(input);
+    let [types, wanted] = input.split_sections()?;
+
+    Ok((
+        types
+            .as_str()
+            .split(", ")
+            .map(|str| str.trim().into())
+            .collect(),
+        wanted.parse_lines(|line| Ok(line.text.trim().into()))?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+";
+
+    #[test]
+    fn test_inputs_do_not_share_counts() {
+        // Same patterns, different towels: counts from the first input must not leak into the second.
+        let only_red = EXAMPLE.replacen("r, wr, b, g, bwu, rb, gb, br", "r", 1);
+
+        assert_eq!(part_one(EXAMPLE).unwrap(), 6);
+        assert_eq!(part_two(EXAMPLE).unwrap(), 16);
+        assert_eq!(part_one(&only_red).unwrap(), 0);
+        assert_eq!(part_two(&only_red).unwrap(), 0);
+    }
+}
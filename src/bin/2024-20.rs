@@ -0,0 +1,128 @@
+use advent_of_code::pos::{Direction, Pos, PosExt};
+use advent_of_code::search::bfs;
+use std::collections::HashSet;
+
+advent_of_code::solution!(2024, 20, variants = [2 => part_two_walk], params = Params);
+
+advent_of_code::params! {
+    pub struct Params {
+        save_minimum: usize = 100,
+    }
+}
+
+type Point = Pos;
+
+pub fn part_one(input: &str, params: &Params) -> Option<usize> {
+    let (start, end, racetrack) = parse(input);
+    let track_vector = generate_racetrack_list(start, &end, &racetrack);
+
+    Some(num_cheats(&track_vector, params.save_minimum, 2))
+}
+
+pub fn part_two(input: &str, params: &Params) -> Option<usize> {
+    let (start, end, racetrack) = parse(input);
+    let track_vector = generate_racetrack_list(start, &end, &racetrack);
+
+    Some(num_cheats(&track_vector, params.save_minimum, 20))
+}
+
+pub fn part_two_walk(input: &str, params: &Params) -> Option<usize> {
+    let (start, end, racetrack) = parse(input);
+    let track_vector = walk_racetrack(start, &end, &racetrack);
+
+    Some(num_cheats(&track_vector, params.save_minimum, 20))
+}
+
+fn generate_racetrack_list(start: Point, end: &Point, racetrack: &HashSet<Point>) -> Vec<Point> {
+    bfs(
+        start,
+        |point| neighbours(point, racetrack),
+        |point| point == end,
+    )
+    .path()
+    .expect("the racetrack should lead to the end")
+}
+
+/// The racetrack is a single path, so it can be walked from the start without tracking the cost of each point.
+fn walk_racetrack(start: Point, end: &Point, racetrack: &HashSet<Point>) -> Vec<Point> {
+    let mut track = vec![start];
+    let mut previous = None;
+
+    while track.last() != Some(end) {
+        let point = *track.last().unwrap();
+        let next = neighbours(&point, racetrack)
+            .into_iter()
+            .find(|n| Some(*n) != previous)
+            .expect("the racetrack should lead to the end");
+
+        previous = Some(point);
+        track.push(next);
+    }
+
+    track
+}
+
+fn neighbours(point: &Point, racetrack: &HashSet<Point>) -> Vec<Point> {
+    Direction::CARDINAL
+        .into_iter()
+        .map(|direction| point.step(direction))
+        .filter(|p| racetrack.contains(p))
+        .collect()
+}
+
+fn num_cheats(racetrack: &[Point], save_minimum: usize, max_distance: isize) -> usize {
+    racetrack
+        .iter()
+        .enumerate()
+        .map(|(point_cost, point)| {
+            racetrack
+                .iter()
+                .enumerate()
+                .skip(point_cost + save_minimum)
+                // map to manhattan distance between points, and cost of target point
+                .map(|(cost, n)| (n.manhattan(*point), cost))
+                // only target points within max distance from the source point
+                .filter(|(distance, _)| *distance <= max_distance)
+                // only points that would save enough to make the cheat worth it
+                .filter(|(distance, cost)| {
+                    let cheat_cost = point_cost + *distance as usize;
+
+                    cheat_cost + save_minimum <= *cost
+                })
+                .count()
+        })
+        .sum::<usize>()
+}
+
+fn parse(input: &str) -> (Point, Point, HashSet<Point>) {
+    let mut start = None;
+    let mut end = None;
+
+    let racetrack = input
+        .lines()
+        .enumerate()
+        .flat_map(|(r, row)| {
+            row.chars()
+                .enumerate()
+                .filter_map(|(c, ch)| {
+                    let point = Point::from_grid((r, c));
+
+                    match ch {
+                        'S' => {
+                            start = Some(point);
+                            Some(point)
+                        }
+                        'E' => {
+                            end = Some(point);
+                            Some(point)
+                        }
+                        '.' => Some(point),
+                        _ => None,
+                    }
+                })
+                .collect::<HashSet<Point>>()
+        })
+        .collect::<HashSet<Point>>();
+
+    (start.unwrap(), end.unwrap(), racetrack)
+}
@@ -1,9 +1,8 @@
-use cached::proc_macro::cached;
 use euclid::{point2, Point2D};
 use itertools::Itertools;
 use std::collections::HashMap;
 
-advent_of_code::solution!(21);
+advent_of_code::solution!(2024, 21);
 
 type Point = Point2D<isize, isize>;
 type Keypad = HashMap<char, Point>;
@@ -24,6 +23,7 @@ fn get_code_score(input: &str, d_pad_robots: usize) -> usize {
         .collect();
 
     let (input_map, directional_map) = build_route_maps();
+    let mut memo = HashMap::new();
 
     let mut score = 0;
     for code in codes.iter() {
@@ -36,7 +36,9 @@ fn get_code_score(input: &str, d_pad_robots: usize) -> usize {
                 std::iter::once(&'A')
                     .chain(p.iter())
                     .tuple_windows()
-                    .map(|(a, b)| solve_min_path(*a, *b, &directional_map, d_pad_robots - 1))
+                    .map(|(a, b)| {
+                        solve_min_path(*a, *b, &directional_map, d_pad_robots - 1, &mut memo)
+                    })
                     .sum::<usize>()
             })
             .min()
@@ -47,12 +49,21 @@ fn get_code_score(input: &str, d_pad_robots: usize) -> usize {
     score
 }
 
-#[cached(key = "String", convert = r#"{ format!("{}{}{}", from, to, depth) }"#)]
-fn solve_min_path(from: char, to: char, routes: &KeypadRoutes, depth: usize) -> usize {
+fn solve_min_path(
+    from: char,
+    to: char,
+    routes: &KeypadRoutes,
+    depth: usize,
+    memo: &mut HashMap<(char, char, usize), usize>,
+) -> usize {
+    if let Some(&length) = memo.get(&(from, to, depth)) {
+        return length;
+    }
+
     let next_paths = routes.get(&(from, to)).unwrap();
     let best_length = next_paths.iter().map(|p| p.len()).min().unwrap();
 
-    if depth == 0 {
+    let length = if depth == 0 {
         best_length
     } else {
         next_paths
@@ -62,12 +73,15 @@ fn solve_min_path(from: char, to: char, routes: &KeypadRoutes, depth: usize) ->
                 std::iter::once(&'A')
                     .chain(p.iter())
                     .tuple_windows()
-                    .map(|(a, b)| solve_min_path(*a, *b, routes, depth - 1))
+                    .map(|(a, b)| solve_min_path(*a, *b, routes, depth - 1, memo))
                     .sum()
             })
             .min()
             .unwrap()
-    }
+    };
+
+    memo.insert((from, to, depth), length);
+    length
 }
 
 fn paths_for(code: &[char], keypad_map: &KeypadRoutes) -> Vec<Vec<char>> {
@@ -170,20 +184,3 @@ fn build_route_maps() -> (KeypadRoutes, KeypadRoutes) {
 
     (input_map, directional_map)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(126384));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(154115708116294));
-    }
-}
@@ -2,7 +2,7 @@ use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
 use std::ops::BitXor;
 
-advent_of_code::solution!(22);
+advent_of_code::solution!(2024, 22);
 
 pub fn part_one(input: &str) -> Option<isize> {
     let numbers = input
@@ -57,7 +57,7 @@ fn get_best_4_sequence_value(sequences: &Vec<Vec<(isize, isize)>>) -> Option<isi
         }
     }
 
-    sequence_values.values().max().map(|x| *x)
+    sequence_values.values().max().copied()
 }
 
 fn process(number: isize, times: isize) -> isize {
@@ -89,18 +89,4 @@ mod tests {
             assert_eq!(secret, expected);
         }
     }
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(37327623));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(23));
-    }
 }
@@ -0,0 +1,28 @@
+use advent_of_code::graph::Graph;
+use itertools::Itertools;
+
+advent_of_code::solution!(2024, 23);
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let network = parse(input);
+
+    let t_three_sets = network
+        .cliques_of_size(3)
+        .into_iter()
+        .filter(|set| set.iter().any(|computer| computer.starts_with('t')))
+        .count();
+
+    Some(t_three_sets)
+}
+
+pub fn part_two(input: &str) -> Option<String> {
+    let network = parse(input);
+
+    let password = network.maximum_clique().into_iter().sorted().join(",");
+
+    Some(password)
+}
+
+fn parse(input: &str) -> Graph<&str> {
+    Graph::undirected(input.lines().map(|l| l.split_once('-').unwrap()))
+}
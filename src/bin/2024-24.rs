@@ -1,9 +1,13 @@
-use crate::Value::{Derived, Raw};
+use advent_of_code::input::{Input, Line};
+use advent_of_code::template::input_error::InputError;
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::ops::{BitAnd, BitOr, BitXor};
+use Value::{Derived, Raw};
 
-advent_of_code::solution!(24);
+advent_of_code::solution!(2024, 24, try_parse = parse);
+
+type Circuit = (Vec<Node>, Vec<String>, HashMap<String, usize>);
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum Operation {
@@ -26,7 +30,7 @@ enum Value {
 }
 
 #[derive(Clone, Debug)]
-struct Node {
+pub struct Node {
     address: usize,
     value: Value,
 }
@@ -40,29 +44,26 @@ impl Node {
                 Raw(value) => *value,
                 Derived(process) => match process.operation {
                     Operation::And => nodes[process.input1]
-                        .derive_value(&nodes, depth - 1)?
-                        .bitand(nodes[process.input2].derive_value(&nodes, depth - 1)?),
+                        .derive_value(nodes, depth - 1)?
+                        .bitand(nodes[process.input2].derive_value(nodes, depth - 1)?),
                     Operation::Or => nodes[process.input1]
-                        .derive_value(&nodes, depth - 1)?
-                        .bitor(nodes[process.input2].derive_value(&nodes, depth - 1)?),
+                        .derive_value(nodes, depth - 1)?
+                        .bitor(nodes[process.input2].derive_value(nodes, depth - 1)?),
                     Operation::Xor => nodes[process.input1]
-                        .derive_value(&nodes, depth - 1)?
-                        .bitxor(nodes[process.input2].derive_value(&nodes, depth - 1)?),
+                        .derive_value(nodes, depth - 1)?
+                        .bitxor(nodes[process.input2].derive_value(nodes, depth - 1)?),
                 },
             })
         }
     }
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let (nodes, _, address_map) = parse(input);
-
-    collated_values('z', &nodes, &address_map)
+pub fn part_one((nodes, _, address_map): &Circuit) -> Option<usize> {
+    collated_values('z', nodes, address_map)
 }
 
-pub fn part_two(input: &str) -> Option<String> {
-    let (nodes, addresses, _) = parse(input);
-    let invalid = find_wrong_nodes(&nodes, &addresses);
+pub fn part_two((nodes, addresses, _): &Circuit) -> Option<String> {
+    let invalid = find_wrong_nodes(nodes, addresses);
 
     Some(invalid.iter().sorted().join(","))
 }
@@ -73,7 +74,7 @@ fn is_intermediate(address: &str) -> bool {
     last_char.is_ascii_alphabetic()
 }
 
-fn find_wrong_nodes(nodes: &Vec<Node>, addresses: &Vec<String>) -> HashSet<String> {
+fn find_wrong_nodes(nodes: &[Node], addresses: &[String]) -> HashSet<String> {
     let operations = nodes
         .iter()
         .filter_map(|n| match &n.value {
@@ -133,7 +134,7 @@ fn collated_values(
         .iter()
         .filter(|(k, _)| k.starts_with(prefix))
         .sorted_by(|(a, _), (b, _)| a.cmp(b))
-        .map(|(_, &address)| nodes[address].derive_value(&nodes, 100))
+        .map(|(_, &address)| nodes[address].derive_value(nodes, 100))
         .collect::<Vec<Option<usize>>>();
 
     if values.iter().any(|x| x.is_none()) {
@@ -149,75 +150,76 @@ fn collated_values(
     }
 }
 
-fn parse(input: &str) -> (Vec<Node>, Vec<String>, HashMap<String, usize>) {
+fn parse(input: &str) -> Result<Circuit, InputError> {
+    let input = Input::new(input);
+    let [wires, gates] = input.split_sections()?;
+
     let mut address_map: HashMap<String, usize> = HashMap::new();
     let mut addresses = Vec::new();
     let mut nodes = Vec::new();
-    let mut lines = input
-        .lines()
-        .filter(|l| !l.is_empty())
-        .collect::<VecDeque<&str>>();
 
-    while let Some(line) = lines.pop_front() {
-        if line.contains(":") {
-            let (address_str, value) = line.split_once(": ").unwrap();
-            let address = nodes.len();
-            address_map.insert(address_str.to_owned(), address);
-            addresses.push(address_str.to_owned());
-
-            nodes.push(Node {
-                address,
-                value: Raw(value.parse::<usize>().unwrap()),
-            })
-        } else {
-            let parts = line.split(" ").collect::<Vec<&str>>();
-
-            let Some(&address1) = address_map.get(parts[0]) else {
-                lines.push_back(line);
-
-                continue;
-            };
-            let Some(&address2) = address_map.get(parts[2]) else {
-                lines.push_back(line);
-
-                continue;
-            };
-
-            // everyone loves a consistent ordering!
-            let (address1, address2) = (address1.min(address2), address1.max(address2));
-
-            let address = nodes.len();
-            address_map.insert(parts[4].to_owned(), address);
-            addresses.push(parts[4].to_owned());
-
-            nodes.push(Node {
-                address,
-                value: Derived(Process {
-                    input1: address1,
-                    input2: address2,
-                    operation: match parts[1] {
-                        "AND" => Operation::And,
-                        "OR" => Operation::Or,
-                        "XOR" => Operation::Xor,
-                        _ => {
-                            unreachable!()
-                        }
-                    },
-                }),
-            })
-        }
+    for line in wires.lines() {
+        let (address_str, value) = line.key_value(":")?;
+        let address = nodes.len();
+        address_map.insert(address_str.to_owned(), address);
+        addresses.push(address_str.to_owned());
+
+        nodes.push(Node {
+            address,
+            value: Raw(value
+                .parse::<usize>()
+                .map_err(|e| line.error_at(value, e))?),
+        })
     }
 
-    (nodes, addresses, address_map)
-}
+    let mut lines = gates.lines().collect::<VecDeque<Line>>();
+    // the number of gates put back in a row, once every gate left was put back none of their inputs will ever be set.
+    let mut deferred = 0;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    while let Some(line) = lines.pop_front() {
+        if deferred > lines.len() {
+            return Err(line.error("the inputs of this gate are never set"));
+        }
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2024));
+        let parts = line.text.split(" ").collect::<Vec<&str>>();
+        let [input1, operation, input2, "->", output] = parts[..] else {
+            return Err(line.error("expected a gate like `x00 AND y00 -> z00`"));
+        };
+
+        let operation = match operation {
+            "AND" => Operation::And,
+            "OR" => Operation::Or,
+            "XOR" => Operation::Xor,
+            _ => {
+                return Err(line.error_at(operation, "expected AND, OR or XOR"));
+            }
+        };
+
+        let (Some(&address1), Some(&address2)) = (address_map.get(input1), address_map.get(input2))
+        else {
+            lines.push_back(line);
+            deferred += 1;
+
+            continue;
+        };
+        deferred = 0;
+
+        // everyone loves a consistent ordering!
+        let (address1, address2) = (address1.min(address2), address1.max(address2));
+
+        let address = nodes.len();
+        address_map.insert(output.to_owned(), address);
+        addresses.push(output.to_owned());
+
+        nodes.push(Node {
+            address,
+            value: Derived(Process {
+                input1: address1,
+                input2: address2,
+                operation,
+            }),
+        })
     }
+
+    Ok((nodes, addresses, address_map))
 }
@@ -0,0 +1,60 @@
+use advent_of_code::grid::Grid;
+use advent_of_code::input::Input;
+use advent_of_code::template::input_error::InputError;
+
+advent_of_code::solution!(2024, 25);
+
+type Heights = Vec<Vec<usize>>;
+
+pub fn part_one(input: &str) -> Result<usize, InputError> {
+    let (locks, keys) = parse(input)?;
+
+    Ok(locks
+        .iter()
+        .map(|l| keys.iter().filter(|k| fits(l, k)).count())
+        .sum())
+}
+
+pub fn part_two(_input: &str) -> Option<u32> {
+    None // no part 2 on 25
+}
+
+fn fits(lock: &[usize], key: &[usize]) -> bool {
+    lock.iter().enumerate().all(|(i, &gap)| key[i] <= gap) // tooth <= gap
+}
+
+fn parse(input: &str) -> Result<(Heights, Heights), InputError> {
+    let key_locks = Input::new(input)
+        .sections()
+        .iter()
+        .map(|section| section.grid(Some))
+        .collect::<Result<Vec<Grid<char>>, InputError>>()?;
+
+    let locks = key_locks
+        .iter()
+        .filter(|m| m.row(0).iter().all(|ch| *ch == '#'))
+        .map(keyed)
+        .collect();
+
+    let keys = key_locks
+        .iter()
+        .filter(|m| m.row(0).iter().all(|ch| *ch == '.'))
+        .map(keyed)
+        .collect();
+
+    Ok((locks, keys))
+}
+
+fn keyed(grid: &Grid<char>) -> Vec<usize> {
+    grid.columns()
+        .map(|column| {
+            let column = column.collect::<Vec<&char>>();
+
+            // we want the empty spaces (.) for locks, and the # for keys
+            column
+                .iter()
+                .filter(|ch| Some(*ch) == column.last())
+                .count()
+        })
+        .collect()
+}
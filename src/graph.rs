@@ -0,0 +1,589 @@
+/// A graph of labelled nodes, e.g. the connections of a network in the puzzle input.
+///
+/// Labels can be anything hashable, like the `&str` names of the input. Nodes are kept in the order they were added,
+/// and everything the graph returns lists them in that order, so results don't change between runs.
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap};
+use std::fmt::Display;
+use std::hash::Hash;
+
+#[derive(Clone, Debug)]
+pub struct Graph<L> {
+    directed: bool,
+    labels: Vec<L>,
+    indices: HashMap<L, usize>,
+    /// The nodes each node has an edge to.
+    successors: Vec<BTreeSet<usize>>,
+    /// The nodes each node shares an edge with in either direction.
+    neighbours: Vec<BTreeSet<usize>>,
+}
+
+/// A cycle that keeps a directed graph from being sorted, e.g. `a -> b -> a`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle<'a, L>(pub Vec<&'a L>);
+
+impl<L: Display> Display for Cycle<'_, L> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "cycle ")?;
+        for node in &self.0 {
+            write!(f, "{node} -> ")?;
+        }
+        match self.0.first() {
+            Some(first) => write!(f, "{first}"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// How many neighbours the nodes of a graph have.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DegreeStats {
+    pub min: usize,
+    pub max: usize,
+    pub mean: f64,
+    /// The number of nodes with each degree.
+    pub counts: BTreeMap<usize, usize>,
+}
+
+impl<L: Clone + Eq + Hash> Graph<L> {
+    /// An empty graph, where edges go both ways.
+    pub fn new_undirected() -> Self {
+        Self::empty(false)
+    }
+
+    /// An empty graph, where edges go one way.
+    pub fn new_directed() -> Self {
+        Self::empty(true)
+    }
+
+    fn empty(directed: bool) -> Self {
+        Graph {
+            directed,
+            labels: vec![],
+            indices: HashMap::new(),
+            successors: vec![],
+            neighbours: vec![],
+        }
+    }
+
+    /// A graph of edges that go both ways, e.g. `[("kh", "tc"), ("qp", "kh")]`.
+    pub fn undirected(edges: impl IntoIterator<Item = (L, L)>) -> Self {
+        let mut graph = Self::new_undirected();
+        graph.extend(edges);
+        graph
+    }
+
+    /// A graph of edges from the first node to the second.
+    pub fn directed(edges: impl IntoIterator<Item = (L, L)>) -> Self {
+        let mut graph = Self::new_directed();
+        graph.extend(edges);
+        graph
+    }
+
+    /// Add a node without edges, if it isn't in the graph yet.
+    pub fn add_node(&mut self, label: L) {
+        self.index_of(label);
+    }
+
+    /// Add an edge, and its nodes if they aren't in the graph yet.
+    pub fn add_edge(&mut self, from: L, to: L) {
+        let from = self.index_of(from);
+        let to = self.index_of(to);
+
+        self.successors[from].insert(to);
+        if !self.directed {
+            self.successors[to].insert(from);
+        }
+
+        self.neighbours[from].insert(to);
+        self.neighbours[to].insert(from);
+    }
+
+    fn index_of(&mut self, label: L) -> usize {
+        if let Some(&index) = self.indices.get(&label) {
+            return index;
+        }
+
+        let index = self.labels.len();
+        self.labels.push(label.clone());
+        self.indices.insert(label, index);
+        self.successors.push(BTreeSet::new());
+        self.neighbours.push(BTreeSet::new());
+        index
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// The number of edges. An undirected edge counts once.
+    pub fn edge_count(&self) -> usize {
+        let ends: usize = self.successors.iter().map(BTreeSet::len).sum();
+        if self.directed {
+            ends
+        } else {
+            // a loop is its own reverse.
+            let loops = (0..self.len())
+                .filter(|&node| self.successors[node].contains(&node))
+                .count();
+            (ends + loops) / 2
+        }
+    }
+
+    pub fn contains(&self, label: &L) -> bool {
+        self.indices.contains_key(label)
+    }
+
+    /// The nodes, in the order they were added.
+    pub fn nodes(&self) -> impl Iterator<Item = &L> {
+        self.labels.iter()
+    }
+
+    /// Whether there's an edge from one node to the other.
+    pub fn has_edge(&self, from: &L, to: &L) -> bool {
+        match (self.indices.get(from), self.indices.get(to)) {
+            (Some(&from), Some(&to)) => self.successors[from].contains(&to),
+            _ => false,
+        }
+    }
+
+    /// The nodes a node has an edge to. Empty if the node isn't in the graph.
+    pub fn successors(&self, label: &L) -> impl Iterator<Item = &L> {
+        self.labels_of(self.indices.get(label).map(|&node| &self.successors[node]))
+    }
+
+    /// The nodes that share an edge with a node, in either direction.
+    pub fn neighbours(&self, label: &L) -> impl Iterator<Item = &L> {
+        self.labels_of(self.indices.get(label).map(|&node| &self.neighbours[node]))
+    }
+
+    fn labels_of<'a>(&'a self, nodes: Option<&'a BTreeSet<usize>>) -> impl Iterator<Item = &'a L> {
+        nodes.into_iter().flatten().map(|&node| &self.labels[node])
+    }
+
+    fn to_labels(&self, nodes: impl IntoIterator<Item = usize>) -> Vec<&L> {
+        nodes.into_iter().map(|node| &self.labels[node]).collect()
+    }
+
+    /* ---------------------------------------------------------------------- */
+
+    /// The number of neighbours of a node, [`None`] if it isn't in the graph.
+    pub fn degree(&self, label: &L) -> Option<usize> {
+        self.indices
+            .get(label)
+            .map(|&node| self.neighbours[node].len())
+    }
+
+    /// The smallest, largest and mean degree of the nodes, [`None`] for an empty graph.
+    pub fn degree_stats(&self) -> Option<DegreeStats> {
+        let degrees = self.neighbours.iter().map(BTreeSet::len);
+
+        let mut counts = BTreeMap::new();
+        for degree in degrees.clone() {
+            *counts.entry(degree).or_insert(0) += 1;
+        }
+
+        Some(DegreeStats {
+            min: degrees.clone().min()?,
+            max: degrees.clone().max()?,
+            mean: degrees.sum::<usize>() as f64 / self.len() as f64,
+            counts,
+        })
+    }
+
+    /* ---------------------------------------------------------------------- */
+
+    /// The groups of nodes connected to each other, ignoring the direction of edges.
+    pub fn connected_components(&self) -> Vec<Vec<&L>> {
+        let mut component_of = vec![None; self.len()];
+        let mut components = vec![];
+
+        for root in 0..self.len() {
+            if component_of[root].is_some() {
+                continue;
+            }
+
+            let mut component = vec![];
+            let mut stack = vec![root];
+            component_of[root] = Some(components.len());
+
+            while let Some(node) = stack.pop() {
+                component.push(node);
+
+                for &next in &self.neighbours[node] {
+                    if component_of[next].is_none() {
+                        component_of[next] = Some(components.len());
+                        stack.push(next);
+                    }
+                }
+            }
+
+            component.sort_unstable();
+            components.push(self.to_labels(component));
+        }
+
+        components
+    }
+
+    /// The nodes in an order where every edge goes forward, or a cycle if there is no such order. Of the nodes that
+    /// could go next, the one added first goes first. Every edge of an undirected graph is a cycle.
+    pub fn topological_sort(&self) -> Result<Vec<&L>, Cycle<'_, L>> {
+        let mut in_degrees = vec![0; self.len()];
+        for successors in &self.successors {
+            for &next in successors {
+                in_degrees[next] += 1;
+            }
+        }
+
+        let mut ready: BinaryHeap<_> = (0..self.len())
+            .filter(|&node| in_degrees[node] == 0)
+            .map(Reverse)
+            .collect();
+        let mut order = vec![];
+
+        while let Some(Reverse(node)) = ready.pop() {
+            order.push(node);
+
+            for &next in &self.successors[node] {
+                in_degrees[next] -= 1;
+                if in_degrees[next] == 0 {
+                    ready.push(Reverse(next));
+                }
+            }
+        }
+
+        if order.len() == self.len() {
+            return Ok(self.to_labels(order));
+        }
+
+        Err(Cycle(self.to_labels(self.find_cycle(&in_degrees))))
+    }
+
+    /// A cycle among the nodes left with incoming edges after sorting, each of which has a predecessor left as well.
+    fn find_cycle(&self, in_degrees: &[usize]) -> Vec<usize> {
+        let is_left = |node: usize| in_degrees[node] > 0;
+        let predecessor = |node: usize| {
+            (0..self.len())
+                .find(|&previous| is_left(previous) && self.successors[previous].contains(&node))
+                .expect("nodes left after sorting should have a predecessor left")
+        };
+
+        // walk backwards until a node repeats, the walk from there on is the cycle.
+        let mut walk = vec![(0..self.len()).find(|&node| is_left(node)).unwrap()];
+        let mut seen = HashMap::from([(walk[0], 0)]);
+
+        loop {
+            let previous = predecessor(*walk.last().unwrap());
+
+            if let Some(&start) = seen.get(&previous) {
+                let mut cycle = walk.split_off(start);
+                cycle.reverse();
+
+                // start at the node added first, so that the same cycle is always reported the same way.
+                let first = (0..cycle.len()).min_by_key(|&i| cycle[i]).unwrap();
+                cycle.rotate_left(first);
+                return cycle;
+            }
+
+            seen.insert(previous, walk.len());
+            walk.push(previous);
+        }
+    }
+
+    /* ---------------------------------------------------------------------- */
+
+    /// The cliques that can't be extended with another node, found with Bron–Kerbosch and pivoting. Edges are taken
+    /// to go both ways.
+    pub fn maximal_cliques(&self) -> Vec<Vec<&L>> {
+        let mut cliques = vec![];
+        self.bron_kerbosch(&mut vec![], (0..self.len()).collect(), vec![], &mut cliques);
+
+        cliques
+            .into_iter()
+            .map(|mut clique| {
+                clique.sort_unstable();
+                self.to_labels(clique)
+            })
+            .collect()
+    }
+
+    /// The largest clique, the first one found if there are several.
+    pub fn maximum_clique(&self) -> Vec<&L> {
+        self.maximal_cliques()
+            .into_iter()
+            .rev()
+            .max_by_key(Vec::len)
+            .unwrap_or_default()
+    }
+
+    fn bron_kerbosch(
+        &self,
+        clique: &mut Vec<usize>,
+        mut candidates: Vec<usize>,
+        mut excluded: Vec<usize>,
+        cliques: &mut Vec<Vec<usize>>,
+    ) {
+        if candidates.is_empty() {
+            if excluded.is_empty() {
+                cliques.push(clique.clone());
+            }
+            return;
+        }
+
+        // any maximal clique contains the pivot or one of its non-neighbours, the pivot with the most neighbours
+        // among the candidates leaves the fewest branches.
+        let pivot = candidates
+            .iter()
+            .chain(&excluded)
+            .copied()
+            .max_by_key(|&pivot| {
+                candidates
+                    .iter()
+                    .filter(|&&node| self.adjacent(pivot, node))
+                    .count()
+            })
+            .unwrap();
+
+        let branches: Vec<usize> = candidates
+            .iter()
+            .copied()
+            .filter(|&node| !self.adjacent(pivot, node))
+            .collect();
+
+        for node in branches {
+            clique.push(node);
+            self.bron_kerbosch(
+                clique,
+                candidates
+                    .iter()
+                    .copied()
+                    .filter(|&other| self.adjacent(node, other))
+                    .collect(),
+                excluded
+                    .iter()
+                    .copied()
+                    .filter(|&other| self.adjacent(node, other))
+                    .collect(),
+                cliques,
+            );
+            clique.pop();
+
+            candidates.retain(|&other| other != node);
+            excluded.push(node);
+        }
+    }
+
+    /// Every set of `size` nodes that are all connected to each other, e.g. the triangles for a size of 3. Edges are
+    /// taken to go both ways.
+    pub fn cliques_of_size(&self, size: usize) -> Vec<Vec<&L>> {
+        let mut cliques = vec![];
+        self.extend_clique(&mut vec![], (0..self.len()).collect(), size, &mut cliques);
+
+        cliques
+            .into_iter()
+            .map(|clique| self.to_labels(clique))
+            .collect()
+    }
+
+    /// Extend a clique with candidates that come after its last node, so that each clique is found once.
+    fn extend_clique(
+        &self,
+        clique: &mut Vec<usize>,
+        candidates: Vec<usize>,
+        size: usize,
+        cliques: &mut Vec<Vec<usize>>,
+    ) {
+        if clique.len() == size {
+            cliques.push(clique.clone());
+            return;
+        }
+
+        for (i, &node) in candidates.iter().enumerate() {
+            // not enough candidates left to fill the clique.
+            if clique.len() + candidates.len() - i < size {
+                break;
+            }
+
+            clique.push(node);
+            self.extend_clique(
+                clique,
+                candidates[i + 1..]
+                    .iter()
+                    .copied()
+                    .filter(|&other| self.adjacent(node, other))
+                    .collect(),
+                size,
+                cliques,
+            );
+            clique.pop();
+        }
+    }
+
+    /// Whether two different nodes are neighbours. A self-loop doesn't make a node its own neighbour in a clique.
+    fn adjacent(&self, a: usize, b: usize) -> bool {
+        a != b && self.neighbours[a].contains(&b)
+    }
+}
+
+impl<L: Clone + Eq + Hash> Extend<(L, L)> for Graph<L> {
+    fn extend<T: IntoIterator<Item = (L, L)>>(&mut self, edges: T) {
+        for (from, to) in edges {
+            self.add_edge(from, to);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{Cycle, DegreeStats, Graph};
+
+    /// The example of 2024 day 23.
+    const NETWORK: &str = "kh-tc qp-kh de-cg ka-co yn-aq qp-ub cg-tb vc-aq tb-ka wh-tc yn-cg kh-ub ta-co de-co \
+        tc-td tb-wq wh-td ta-ka td-qp aq-cg wq-ub ub-vc de-ta wq-aq wq-vc wh-yn ka-de kh-ta co-tc wh-qp tb-vc td-yn";
+
+    fn network() -> Graph<&'static str> {
+        Graph::undirected(
+            NETWORK
+                .split_whitespace()
+                .map(|edge| edge.split_once('-').unwrap()),
+        )
+    }
+
+    fn sorted<'a>(mut nodes: Vec<&&'a str>) -> Vec<&'a str> {
+        nodes.sort();
+        nodes.into_iter().copied().collect()
+    }
+
+    #[test]
+    fn builds_graphs() {
+        let graph = network();
+        assert_eq!(graph.len(), 16);
+        assert_eq!(graph.edge_count(), 32);
+        assert!(graph.has_edge(&"kh", &"tc"));
+        assert!(graph.has_edge(&"tc", &"kh"));
+        assert!(!graph.has_edge(&"kh", &"de"));
+        assert_eq!(
+            sorted(graph.neighbours(&"kh").collect()),
+            ["qp", "ta", "tc", "ub"]
+        );
+        assert_eq!(graph.successors(&"xx").count(), 0);
+
+        let graph = Graph::directed([("a", "b"), ("b", "a"), ("b", "c")]);
+        assert_eq!(graph.edge_count(), 3);
+        assert!(!graph.has_edge(&"c", &"b"));
+        assert_eq!(graph.neighbours(&"c").collect::<Vec<_>>(), [&"b"]);
+    }
+
+    #[test]
+    fn finds_cliques() {
+        let graph = network();
+
+        let triangles = graph.cliques_of_size(3);
+        assert_eq!(triangles.len(), 12);
+        assert_eq!(
+            triangles
+                .iter()
+                .filter(|clique| clique.iter().any(|node| node.starts_with('t')))
+                .count(),
+            7
+        );
+
+        assert_eq!(sorted(graph.maximum_clique()), ["co", "de", "ka", "ta"]);
+
+        // every maximal clique is a clique, and none contains another.
+        let cliques = graph.maximal_cliques();
+        for clique in &cliques {
+            for (a, b) in clique.iter().zip(clique.iter().skip(1)) {
+                assert!(graph.has_edge(a, b));
+            }
+            assert!(!cliques.iter().any(|other| other.len() > clique.len()
+                && clique.iter().all(|node| other.contains(node))));
+        }
+
+        assert_eq!(graph.cliques_of_size(5), Vec::<Vec<&&str>>::new());
+        assert_eq!(
+            Graph::<u8>::new_undirected().maximum_clique(),
+            Vec::<&u8>::new()
+        );
+    }
+
+    #[test]
+    fn finds_cliques_with_self_loops() {
+        let graph = Graph::undirected([("a", "a"), ("a", "b"), ("b", "c"), ("c", "a"), ("d", "d")]);
+
+        assert_eq!(
+            graph.maximal_cliques(),
+            [vec![&"a", &"b", &"c"], vec![&"d"]]
+        );
+        assert_eq!(sorted(graph.maximum_clique()), ["a", "b", "c"]);
+        assert_eq!(graph.cliques_of_size(2).len(), 3);
+        assert_eq!(graph.cliques_of_size(3).len(), 1);
+    }
+
+    #[test]
+    fn finds_components() {
+        let mut graph = Graph::undirected([(1, 2), (3, 4), (2, 5)]);
+        graph.add_node(6);
+
+        assert_eq!(
+            graph.connected_components(),
+            [vec![&1, &2, &5], vec![&3, &4], vec![&6]]
+        );
+        assert_eq!(network().connected_components().len(), 1);
+    }
+
+    #[test]
+    fn sorts_topologically() {
+        let graph = Graph::directed([
+            ("shirt", "tie"),
+            ("tie", "jacket"),
+            ("trousers", "shoes"),
+            ("shirt", "jacket"),
+        ]);
+        assert_eq!(
+            graph.topological_sort(),
+            Ok(vec![&"shirt", &"tie", &"jacket", &"trousers", &"shoes"])
+        );
+
+        let graph = Graph::directed([(1, 2), (2, 3), (3, 4), (4, 2), (0, 1)]);
+        let Err(cycle) = graph.topological_sort() else {
+            panic!("expected a cycle");
+        };
+        assert_eq!(cycle, Cycle(vec![&2, &3, &4]));
+        assert_eq!(cycle.to_string(), "cycle 2 -> 3 -> 4 -> 2");
+    }
+
+    #[test]
+    fn measures_degrees() {
+        let graph = network();
+        assert_eq!(graph.degree(&"kh"), Some(4));
+        assert_eq!(graph.degree(&"xx"), None);
+        assert_eq!(
+            graph.degree_stats(),
+            Some(DegreeStats {
+                min: 4,
+                max: 4,
+                mean: 4.0,
+                counts: BTreeMap::from([(4, 16)]),
+            })
+        );
+
+        let graph = Graph::undirected([(1, 2), (1, 3)]);
+        let stats = graph.degree_stats().unwrap();
+        assert_eq!((stats.min, stats.max), (1, 2));
+        assert!((stats.mean - 4.0 / 3.0).abs() < 1e-9);
+        assert_eq!(Graph::<u8>::new_directed().degree_stats(), None);
+    }
+}
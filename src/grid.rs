@@ -0,0 +1,456 @@
+/// A rectangular grid of cells, e.g. a map parsed from the puzzle input.
+///
+/// Positions are `(row, column)`, counted from the top left. Cells are stored row by row.
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+use crate::template::input_error::InputError;
+
+/// Steps to the 4 orthogonal neighbours of a cell, as `(row, column)` deltas: up, right, down, left.
+pub const DELTAS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Steps to the 8 neighbours of a cell, clockwise from up.
+pub const DELTAS_8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// A grid of rows, [`None`] if they differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parse a grid with a cell per character. Fails if the lines differ in length.
+    ///
+    /// ```
+    /// # use advent_of_code::grid::Grid;
+    /// let grid = Grid::parse("#.\n.#", |c| c == '#').unwrap();
+    /// assert_eq!(grid[(1, 1)], true);
+    /// ```
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Self, InputError> {
+        Self::try_parse(input, |c| Some(cell(c)))
+    }
+
+    /// Parse a grid with a cell per character. Fails at characters that `cell` doesn't map, or if the lines differ
+    /// in length.
+    pub fn try_parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, InputError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (index, line) in input.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            let start = cells.len();
+
+            for (column, c) in line.chars().enumerate() {
+                let value = cell(c).ok_or_else(|| {
+                    InputError::on_line(index, line, format!("unexpected character `{c}`"))
+                        .at_column(column)
+                })?;
+                cells.push(value);
+            }
+
+            let line_width = cells.len() - start;
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    return Err(InputError::on_line(
+                        index,
+                        line,
+                        format!("expected {width} cells, found {line_width}"),
+                    ));
+                }
+                _ => {}
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, (row, column): (usize, usize)) -> bool {
+        row < self.height && column < self.width
+    }
+
+    /// The cell at a position, [`None`] if it is out of bounds.
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        self.in_bounds(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        if self.in_bounds(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// The position one step of `delta` away from `pos`, [`None`] if it is out of bounds.
+    pub fn offset(
+        &self,
+        (row, column): (usize, usize),
+        (d_row, d_column): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let pos = (
+            row.checked_add_signed(d_row)?,
+            column.checked_add_signed(d_column)?,
+        );
+        self.in_bounds(pos).then_some(pos)
+    }
+
+    /// The positions from `pos` in steps of `delta`, starting with `pos`, until the edge of the grid.
+    pub fn ray(
+        &self,
+        pos: (usize, usize),
+        delta: (isize, isize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let start = self.in_bounds(pos).then_some(pos);
+        std::iter::successors(start, move |pos| self.offset(*pos, delta))
+    }
+
+    /// The orthogonal neighbours of a position within the grid.
+    pub fn neighbours_4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        DELTAS_4
+            .into_iter()
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    /// The orthogonal and diagonal neighbours of a position within the grid.
+    pub fn neighbours_8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        DELTAS_8
+            .into_iter()
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell that matches, row by row.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// The positions of all cells that match, row by row.
+    pub fn find_all<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(column < self.width, "column {column} is out of bounds");
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    /// The diagonals from the top left to the bottom right, starting with the one at the bottom left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (width, height) = self.diagonal_bounds();
+        let starts = (0..height).rev().map(|row| (row, 0));
+        let starts = starts.chain((1..width).map(|column| (0, column)));
+        starts.map(|start| self.ray(start, (1, 1)).map(|pos| &self[pos]))
+    }
+
+    /// The diagonals from the top right to the bottom left, starting with the one at the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (width, height) = self.diagonal_bounds();
+        let starts = (0..width).map(|column| (0, column));
+        let starts = starts.chain((1..height).map(move |row| (row, width - 1)));
+        starts.map(|start| self.ray(start, (1, -1)).map(|pos| &self[pos]))
+    }
+
+    /// The size of the grid to start diagonals from. A grid without cells has none, even if it has rows or columns.
+    fn diagonal_bounds(&self) -> (usize, usize) {
+        if self.cells.is_empty() {
+            (0, 0)
+        } else {
+            (self.width, self.height)
+        }
+    }
+
+    /// A grid of the same size with each cell mapped.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// The grid mirrored along its main diagonal, its rows become its columns.
+    #[must_use]
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(row, column)| (column, row))
+    }
+
+    /// The grid rotated by 90° clockwise.
+    #[must_use]
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(row, column)| {
+            (self.height - 1 - column, row)
+        })
+    }
+
+    /// The grid rotated by 90° counterclockwise.
+    #[must_use]
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(row, column)| {
+            (column, self.width - 1 - row)
+        })
+    }
+
+    /// A grid of the given size, taking each cell from the position `source` maps it to.
+    fn rebuild(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn((usize, usize)) -> (usize, usize),
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let positions = (0..height).flat_map(|row| (0..width).map(move |column| (row, column)));
+
+        Grid {
+            width,
+            height,
+            cells: positions.map(|pos| self[source(pos)].clone()).collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("position {pos:?} is out of bounds"))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("position {pos:?} is out of bounds"))
+    }
+}
+
+/// Prints a line per row, without separators between the cells.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Grid;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef\n", |c| c).unwrap()
+    }
+
+    #[test]
+    fn parses_grids() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 2)], 'f');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(
+            Grid::parse("ab\r\ncd\r\n", |c| c).unwrap().to_string(),
+            "ab\ncd"
+        );
+
+        let error = Grid::parse("abc\nde\n", |c| c).unwrap_err();
+        assert_eq!(error.to_string(), "line 2: expected 3 cells, found 2");
+
+        let error = Grid::try_parse("12\n3x\n", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: unexpected character `x`"
+        );
+
+        assert_eq!(
+            Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap()[(1, 0)],
+            3
+        );
+        assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3]]), None);
+    }
+
+    #[test]
+    fn finds_neighbours() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours_4((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours_8((1, 1)).count(), 5);
+        assert_eq!(grid.offset((0, 2), (0, 1)), None);
+        assert_eq!(grid.offset((0, 2), (1, -1)), Some((1, 1)));
+        assert_eq!(
+            grid.ray((0, 0), (1, 1)).collect::<Vec<_>>(),
+            [(0, 0), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn finds_positions() {
+        let grid = grid();
+        assert_eq!(grid.find(|c| *c == 'e'), Some((1, 1)));
+        assert_eq!(grid.find(|c| *c == 'x'), None);
+        assert_eq!(
+            grid.find_all(|c| "aef".contains(*c)).collect::<Vec<_>>(),
+            [(0, 0), (1, 1), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn iterates_lines() {
+        let grid = grid();
+        let collect = |cells: &mut dyn Iterator<Item = &char>| cells.collect::<String>();
+
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(
+            grid.columns()
+                .map(|mut c| collect(&mut c))
+                .collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+        assert_eq!(
+            grid.diagonals()
+                .map(|mut d| collect(&mut d))
+                .collect::<Vec<_>>(),
+            ["d", "ae", "bf", "c"]
+        );
+        assert_eq!(
+            grid.anti_diagonals()
+                .map(|mut d| collect(&mut d))
+                .collect::<Vec<_>>(),
+            ["a", "bd", "ce", "f"]
+        );
+    }
+
+    #[test]
+    fn iterates_lines_of_empty_grids() {
+        let grid = Grid::parse("\n\n", |c| c).unwrap();
+        assert_eq!((grid.width(), grid.height()), (0, 2));
+        assert_eq!(grid.rows().map(<[char]>::len).collect::<Vec<_>>(), [0, 0]);
+        assert_eq!(grid.columns().count(), 0);
+        assert_eq!(grid.diagonals().count(), 0);
+        assert_eq!(grid.anti_diagonals().count(), 0);
+
+        let grid = Grid::new(2, 0, '.');
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.columns().map(Iterator::count).sum::<usize>(), 0);
+        assert_eq!(grid.diagonals().count(), 0);
+        assert_eq!(grid.anti_diagonals().count(), 0);
+    }
+
+    #[test]
+    fn transforms_grids() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF");
+    }
+}
@@ -0,0 +1,401 @@
+/// Parsing of puzzle inputs that reports broken inputs as an [`InputError`] instead of panicking.
+///
+/// An [`Input`] normalises `\r\n` line endings, splits into sections at blank lines and parses lines into numbers,
+/// key-value pairs or a [`Grid`]. Sections remember where they start, so errors point at the line of the whole input:
+///
+/// ```ignore
+/// let input = Input::new(input);
+/// let [rules, updates] = input.split_sections()?;
+/// let rules = rules.parse_lines(|line| line.ints_exact::<u32, 2>())?;
+/// let updates = updates.parse_lines(|line| line.uints::<u32>())?;
+/// ```
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::grid::Grid;
+use crate::template::input_error::InputError;
+
+/// The puzzle input, or a section of it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Input<'a> {
+    text: Cow<'a, str>,
+    /// The 0-based index of the first line in the whole input.
+    first_line: usize,
+}
+
+/// A line of the input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// 0-based index of the line in the whole input.
+    pub index: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Input<'a> {
+    /// The input with `\r\n` line endings replaced by `\n`. Only allocates if there are any.
+    pub fn new(text: &'a str) -> Self {
+        let text = if text.contains('\r') {
+            Cow::Owned(text.replace("\r\n", "\n"))
+        } else {
+            Cow::Borrowed(text)
+        };
+
+        Input {
+            text,
+            first_line: 0,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// The lines, including blank ones.
+    pub fn lines(&self) -> impl Iterator<Item = Line<'_>> {
+        self.text.lines().enumerate().map(|(index, text)| Line {
+            index: self.first_line + index,
+            text,
+        })
+    }
+
+    /// The groups of lines separated by one or more blank lines.
+    pub fn sections(&self) -> Vec<Input<'_>> {
+        let mut sections = vec![];
+        // the first line of the current section, and where it starts and ends in the text.
+        let mut current: Option<(usize, usize, usize)> = None;
+        let mut offset = 0;
+
+        for (index, line) in self.text.split('\n').enumerate() {
+            let end = offset + line.len();
+
+            if line.trim().is_empty() {
+                sections.extend(current.take().map(|section| self.section(section)));
+            } else {
+                match &mut current {
+                    Some((_, _, section_end)) => *section_end = end,
+                    None => current = Some((index, offset, end)),
+                }
+            }
+
+            offset = end + 1;
+        }
+
+        sections.extend(current.map(|section| self.section(section)));
+        sections
+    }
+
+    fn section(&self, (index, start, end): (usize, usize, usize)) -> Input<'_> {
+        Input {
+            text: Cow::Borrowed(&self.text[start..end]),
+            first_line: self.first_line + index,
+        }
+    }
+
+    /// Exactly `N` sections, e.g. `let [rules, updates] = input.split_sections()?;`.
+    pub fn split_sections<const N: usize>(&self) -> Result<[Input<'_>; N], InputError> {
+        let sections = self.sections();
+        let found = sections.len();
+
+        sections.try_into().map_err(|sections: Vec<Input>| {
+            let message = format!("expected {N} sections, found {found}");
+
+            // point at the first extra section, or the end of the input if sections are missing.
+            match sections.get(N) {
+                Some(extra) => extra.lines().next().unwrap().error(message),
+                None => self.last_line().error(message),
+            }
+        })
+    }
+
+    fn last_line(&self) -> Line<'_> {
+        self.lines().last().unwrap_or(Line {
+            index: self.first_line,
+            text: "",
+        })
+    }
+
+    /// Parse each line that isn't blank.
+    pub fn parse_lines<'s, T>(
+        &'s self,
+        mut parse: impl FnMut(Line<'s>) -> Result<T, InputError>,
+    ) -> Result<Vec<T>, InputError> {
+        self.lines()
+            .filter(|line| !line.text.trim().is_empty())
+            .map(&mut parse)
+            .collect()
+    }
+
+    /// The integers of all lines, see [`Line::ints`].
+    pub fn ints<T>(&self) -> Result<Vec<T>, InputError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.numbers(true)
+    }
+
+    /// The unsigned integers of all lines, see [`Line::uints`].
+    pub fn uints<T>(&self) -> Result<Vec<T>, InputError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.numbers(false)
+    }
+
+    fn numbers<T>(&self, signed: bool) -> Result<Vec<T>, InputError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.lines().flat_map(|line| line.numbers(signed)).collect()
+    }
+
+    /// The key and value of each line that isn't blank, see [`Line::key_value`].
+    pub fn key_values(&self, separator: &str) -> Result<Vec<(&str, &str)>, InputError> {
+        self.parse_lines(|line| line.key_value(separator))
+    }
+
+    /// Parse a grid with a cell per character, see [`Grid::try_parse`].
+    pub fn grid<T>(&self, cell: impl FnMut(char) -> Option<T>) -> Result<Grid<T>, InputError> {
+        Grid::try_parse(&self.text, cell).map_err(|mut error| {
+            error.location.line += self.first_line;
+            error
+        })
+    }
+}
+
+impl<'a> Line<'a> {
+    /// The 1-based line number.
+    pub fn number(self) -> usize {
+        self.index + 1
+    }
+
+    /// An error on the whole line.
+    pub fn error(self, message: impl Display) -> InputError {
+        InputError::on_line(self.index, self.text, message)
+    }
+
+    /// An error at a token of the line. `token` has to be a slice of the line's text.
+    pub fn error_at(self, token: &str, message: impl Display) -> InputError {
+        let mut error = InputError::at(self.text, token, message);
+        error.location.line = self.number();
+        error
+    }
+
+    /// Parse the whole line, without surrounding whitespace.
+    pub fn parse<T>(self) -> Result<T, InputError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let text = self.text.trim();
+        text.parse().map_err(|e| self.error_at(text, e))
+    }
+
+    /// Parse each word of the line, split at whitespace.
+    pub fn words<T>(self) -> Result<Vec<T>, InputError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.text
+            .split_whitespace()
+            .map(|word| word.parse().map_err(|e| self.error_at(word, e)))
+            .collect()
+    }
+
+    /// The integers in the line, anything else separates them. A `-` right before a number makes it negative, unless
+    /// it follows a digit, e.g. `p=0,4 v=3,-3 r=5-7` has the integers 0, 4, 3, -3, 5 and 7.
+    pub fn ints<T>(self) -> Result<Vec<T>, InputError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.numbers(true).collect()
+    }
+
+    /// The integers in the line, taking any `-` as a separator, e.g. `3-5` has the integers 3 and 5.
+    pub fn uints<T>(self) -> Result<Vec<T>, InputError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.numbers(false).collect()
+    }
+
+    /// Exactly `N` integers in the line, see [`Line::ints`].
+    pub fn ints_exact<T, const N: usize>(self) -> Result<[T; N], InputError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let numbers: Vec<T> = self.ints()?;
+        let found = numbers.len();
+
+        numbers
+            .try_into()
+            .map_err(|_| self.error(format!("expected {N} numbers, found {found}")))
+    }
+
+    fn numbers<T>(self, signed: bool) -> impl Iterator<Item = Result<T, InputError>> + 'a
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let bytes = self.text.as_bytes();
+        let mut i = 0;
+
+        std::iter::from_fn(move || {
+            while i < bytes.len() {
+                // NOTE: a `-` between digits is a separator, as in a range like `5-7`.
+                let is_negative = signed
+                    && bytes[i] == b'-'
+                    && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+                    && (i == 0 || !bytes[i - 1].is_ascii_digit());
+
+                if !bytes[i].is_ascii_digit() && !is_negative {
+                    i += 1;
+                    continue;
+                }
+
+                let start = i;
+                i += 1;
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+
+                let token = &self.text[start..i];
+                return Some(token.parse().map_err(|e| self.error_at(token, e)));
+            }
+
+            None
+        })
+    }
+
+    /// The key and value of a line like `x00: 1`, split at `separator` and without surrounding whitespace.
+    pub fn key_value(self, separator: &str) -> Result<(&'a str, &'a str), InputError> {
+        let (key, value) = self
+            .text
+            .split_once(separator)
+            .ok_or_else(|| self.error(format!("expected `{separator}`")))?;
+
+        Ok((key.trim(), value.trim()))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Input, Line};
+
+    #[test]
+    fn normalises_line_endings() {
+        let input = Input::new("1 2\r\n\r\n3\r\n");
+        assert_eq!(input.as_str(), "1 2\n\n3\n");
+        assert_eq!(input.sections().len(), 2);
+        assert_eq!(input.uints::<u8>(), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn splits_sections() {
+        let input = Input::new("a: 1\nb: 2\n\n\n47|53\n  \n61,13\n");
+        let sections = input.sections();
+
+        assert_eq!(sections.len(), 3);
+        assert_eq!(sections[0].as_str(), "a: 1\nb: 2");
+        assert_eq!(sections[1].as_str(), "47|53");
+        assert_eq!(
+            sections[2].lines().collect::<Vec<_>>(),
+            [Line {
+                index: 6,
+                text: "61,13"
+            }]
+        );
+
+        let [values, rules, updates] = input.split_sections().unwrap();
+        assert_eq!(values.key_values(":"), Ok(vec![("a", "1"), ("b", "2")]));
+        assert_eq!(rules.uints::<u32>(), Ok(vec![47, 53]));
+        assert_eq!(updates.uints::<u32>(), Ok(vec![61, 13]));
+
+        let error = input.split_sections::<2>().unwrap_err();
+        assert_eq!(error.to_string(), "line 7: expected 2 sections, found 3");
+        let error = input.split_sections::<4>().unwrap_err();
+        assert_eq!(error.to_string(), "line 7: expected 4 sections, found 3");
+    }
+
+    #[test]
+    fn extracts_integers() {
+        let line = Line {
+            index: 2,
+            text: "p=0,4 v=3,-3 r=5-7",
+        };
+        assert_eq!(line.ints::<i32>(), Ok(vec![0, 4, 3, -3, 5, 7]));
+        assert_eq!(line.uints::<u64>(), Ok(vec![0, 4, 3, 3, 5, 7]));
+        assert_eq!(
+            line.ints::<u8>().unwrap_err().to_string(),
+            "line 3, column 11: invalid digit found in string"
+        );
+
+        let line = Line {
+            index: 0,
+            text: "Button A: X+94, Y+34",
+        };
+        assert_eq!(line.ints_exact::<i64, 2>(), Ok([94, 34]));
+        assert_eq!(
+            line.ints_exact::<i64, 3>().unwrap_err().to_string(),
+            "line 1: expected 3 numbers, found 2"
+        );
+
+        let line = Line {
+            index: 4,
+            text: "7 300",
+        };
+        assert_eq!(
+            line.words::<u8>().unwrap_err().to_string(),
+            "line 5, column 3: number too large to fit in target type"
+        );
+        assert_eq!(line.words::<u16>(), Ok(vec![7, 300]));
+    }
+
+    #[test]
+    fn reports_lines_of_sections() {
+        let input = Input::new("1\n2\n\n3\nx4\n");
+        let [_, second] = input.split_sections().unwrap();
+
+        assert_eq!(
+            second.ints::<u8>(),
+            Ok(vec![3, 4]),
+            "anything but digits separates integers"
+        );
+        assert_eq!(
+            second
+                .parse_lines(|line| line.parse::<u8>())
+                .unwrap_err()
+                .to_string(),
+            "line 5, column 1: invalid digit found in string"
+        );
+        assert_eq!(
+            second.key_values(":").unwrap_err().to_string(),
+            "line 4: expected `:`"
+        );
+    }
+
+    #[test]
+    fn parses_grids() {
+        let input = Input::new("x\n\n#.\n.#\n");
+        let [_, map] = input.split_sections().unwrap();
+
+        let grid = map.grid(|c| Some(c == '#')).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert!(grid[(1, 1)]);
+
+        let error = map.grid(|c| (c == '.').then_some(0)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: unexpected character `#`"
+        );
+    }
+}
@@ -1,3 +1,11 @@
+// NOTE: lets the solutions in `src/bin` refer to the library by name when they are compiled into it.
+extern crate self as advent_of_code;
+
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod pos;
+pub mod search;
 pub mod template;
 
 pub fn transpose2<T>(v: Vec<Vec<T>>) -> Vec<Vec<T>> {
@@ -1,78 +1,173 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{all, download, read, scaffold, solve, time, verify};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
+use advent_of_code::template::Puzzle;
 #[cfg(feature = "today")]
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::budgets::{BudgetSpec, Budgets};
+    use advent_of_code::template::compare::Baseline;
+    use advent_of_code::template::config::{config, load_config};
+    use advent_of_code::template::solution_args::SolutionArgs;
+    use advent_of_code::template::{Day, Puzzle, Year};
+    use std::{env, ffi::OsString, mem, process};
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: Puzzle,
         },
         Read {
-            day: Day,
+            puzzle: Puzzle,
         },
         Scaffold {
-            day: Day,
+            puzzle: Puzzle,
             download: bool,
             overwrite: bool,
         },
         Solve {
-            day: Day,
+            puzzle: Puzzle,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            all_inputs: bool,
+            solution_args: SolutionArgs,
         },
         All {
+            year: Year,
             release: bool,
+            isolated: bool,
+            budgets: Budgets,
         },
         Time {
+            year: Year,
             all: bool,
             day: Option<Day>,
             store: bool,
+            isolated: bool,
+            compare: Option<Baseline>,
+            threshold: Option<f64>,
+            budgets: Budgets,
+        },
+        TimeVariants {
+            puzzle: Puzzle,
+            budgets: Budgets,
+        },
+        Verify {
+            year: Year,
+            store: bool,
+            budgets: Budgets,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
-        let mut args = pico_args::Arguments::from_env();
+        // the default flags of a command in `aoc.toml` are added to the ones passed.
+        let args: Vec<OsString> = env::args_os().skip(1).collect();
+        let mut args = pico_args::Arguments::from_vec(load_config()?.with_flags(args));
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
+                year: parse_year(&mut args)?,
                 release: args.contains("--release"),
+                isolated: args.contains("--isolated"),
+                budgets: parse_budgets(&mut args)?,
             },
             Some("time") => {
+                let year = parse_year(&mut args)?;
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let isolated = args.contains("--isolated");
+                let variants = args.contains("--variants");
+                let baseline: Option<Baseline> = args.opt_value_from_str("--baseline")?;
+                let threshold: Option<f64> = args.opt_value_from_str("--threshold")?;
+                let budgets = parse_budgets(&mut args)?;
+
+                // a baseline or threshold only makes sense when comparing, so they imply `--compare`.
+                let compare =
+                    if args.contains("--compare") || baseline.is_some() || threshold.is_some() {
+                        Some(baseline.unwrap_or(Baseline::Previous))
+                    } else {
+                        None
+                    };
+
+                let day: Option<Day> = args.opt_free_from_str()?;
+                if let Some(day) = day {
+                    check_day(year, day)?;
+                }
+
+                if variants {
+                    let Some(day) = day else {
+                        return Err("`--variants` needs a day.".into());
+                    };
+
+                    if store || compare.is_some() {
+                        return Err(
+                            "`--variants` can't be combined with `--store` or `--compare`.".into(),
+                        );
+                    }
 
-                AppArguments::Time {
-                    all,
-                    day: args.opt_free_from_str()?,
-                    store,
+                    AppArguments::TimeVariants {
+                        puzzle: Puzzle::new(year, day).unwrap(),
+                        budgets,
+                    }
+                } else {
+                    AppArguments::Time {
+                        year,
+                        all,
+                        day,
+                        store,
+                        isolated,
+                        compare,
+                        threshold,
+                        budgets,
+                    }
                 }
             }
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+            Some("solve") => {
+                let puzzle = parse_puzzle(&mut args)?;
+                let release = args.contains("--release");
+                let submit = args.opt_value_from_str("--submit")?;
+                let dhat = args.contains("--dhat");
+                let all_inputs = args.contains("--all-inputs");
+                let solution_args = parse_solution_args(&mut args)?;
+
+                if all_inputs
+                    && (submit.is_some() || dhat || solution_args != SolutionArgs::default())
+                {
+                    return Err(
+                        "`--all-inputs` can't be combined with `--input`, `--example`, `--part`, \
+                        `--submit` or `--dhat`."
+                            .into(),
+                    );
+                }
+
+                AppArguments::Solve {
+                    puzzle,
+                    release,
+                    dhat,
+                    submit,
+                    all_inputs,
+                    solution_args,
+                }
+            }
+            Some("verify") => AppArguments::Verify {
+                year: parse_year(&mut args)?,
+                store: args.contains("--store"),
+                budgets: parse_budgets(&mut args)?,
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -93,6 +188,66 @@ mod args {
 
         Ok(app_args)
     }
+
+    /// Parse the `--year` option, falling back to the year of `aoc.toml`.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--year")? {
+            Some(year) => Ok(year),
+            None => config().year.ok_or_else(|| {
+                "no year specified. Pass `--year <year>` or set `year` in `aoc.toml`.".into()
+            }),
+        }
+    }
+
+    /// Parse the `--budget <duration>` and `--budget <day>=<duration>` options, which may be repeated.
+    /// They override the budgets of `aoc.toml`.
+    fn parse_budgets(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Budgets, Box<dyn std::error::Error>> {
+        let mut budgets = config().budgets.clone();
+        budgets.extend(args.values_from_str::<_, BudgetSpec>("--budget")?);
+        Ok(budgets)
+    }
+
+    /// Parse the options that are passed on to the solution binary from the remaining arguments.
+    /// pico-args doesn't support options with an optional value like `--example [N]`, so they are parsed separately.
+    fn parse_solution_args(
+        args: &mut pico_args::Arguments,
+    ) -> Result<SolutionArgs, Box<dyn std::error::Error>> {
+        let remaining = mem::replace(args, pico_args::Arguments::from_vec(vec![])).finish();
+        let remaining: Vec<String> = remaining
+            .into_iter()
+            .map(|arg| {
+                arg.into_string()
+                    .map_err(|_| "arguments must be valid UTF-8.")
+            })
+            .collect::<Result<_, _>>()?;
+
+        let (solution_args, unknown) = SolutionArgs::parse_known(&remaining)?;
+        *args = pico_args::Arguments::from_vec(unknown.into_iter().map(Into::into).collect());
+        Ok(solution_args)
+    }
+
+    /// Parse the `--year` option and the day argument into a puzzle.
+    /// The year is parsed first, as free arguments have to come last.
+    fn parse_puzzle(args: &mut pico_args::Arguments) -> Result<Puzzle, Box<dyn std::error::Error>> {
+        let year = parse_year(args)?;
+        let day = args.free_from_str()?;
+        check_day(year, day)?;
+        Ok(Puzzle::new(year, day).unwrap())
+    }
+
+    fn check_day(year: Year, day: Day) -> Result<(), Box<dyn std::error::Error>> {
+        if year.has_day(day) {
+            Ok(())
+        } else {
+            Err(format!(
+                "advent of code {year} only has {} days, day {day} does not exist.",
+                year.day_count()
+            )
+            .into())
+        }
+    }
 }
 
 fn main() {
@@ -102,38 +257,70 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold {
+            AppArguments::All {
+                year,
+                release,
+                isolated,
+                budgets,
+            } => all::handle(year, release, isolated, &budgets),
+            AppArguments::Time {
+                year,
                 day,
+                all,
+                store,
+                isolated,
+                compare,
+                threshold,
+                budgets,
+            } => time::handle(
+                year, day, all, store, isolated, compare, threshold, &budgets,
+            ),
+            AppArguments::TimeVariants { puzzle, budgets } => {
+                time::handle_variants(puzzle, &budgets)
+            }
+            AppArguments::Verify {
+                year,
+                store,
+                budgets,
+            } => verify::handle(year, store, &budgets),
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Scaffold {
+                puzzle,
                 download,
                 overwrite,
             } => {
-                scaffold::handle(day, overwrite);
+                scaffold::handle(puzzle, overwrite);
                 if download {
-                    download::handle(day);
+                    download::handle(puzzle);
                 }
             }
             AppArguments::Solve {
-                day,
+                puzzle,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                all_inputs,
+                solution_args,
+            } => {
+                if all_inputs {
+                    solve::handle_all_inputs(puzzle, release);
+                } else {
+                    solve::handle(puzzle, release, dhat, submit, solution_args);
+                }
+            }
             #[cfg(feature = "today")]
             AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                match Puzzle::today() {
+                    Some(puzzle) => {
+                        scaffold::handle(puzzle, false);
+                        download::handle(puzzle);
+                        read::handle(puzzle)
                     }
                     None => {
                         eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
+                            "`today` command can only be run during advent. \
+                            Please use `scaffold` with a specific day."
                         );
                         process::exit(1)
                     }
@@ -0,0 +1,264 @@
+/// Positions on a map and the directions to move them in.
+///
+/// A [`Pos`] is a [`euclid::Point2D`] with the row as `x` and the column as `y`, so that it orders and prints like the
+/// `(row, column)` positions of a [`Grid`](crate::grid::Grid). North is up, towards row 0.
+use std::str::FromStr;
+
+use euclid::{point2, vec2, Box2D, Point2D, Vector2D};
+
+pub type Pos = Point2D<isize, isize>;
+pub type Vector = Vector2D<isize, isize>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The cardinal directions, clockwise from north.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// The diagonal directions, clockwise from north east.
+    pub const DIAGONAL: [Direction; 4] = [
+        Direction::NorthEast,
+        Direction::SouthEast,
+        Direction::SouthWest,
+        Direction::NorthWest,
+    ];
+
+    /// All directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// The step of this direction as a `(row, column)` delta, e.g. for [`Grid::offset`](crate::grid::Grid::offset).
+    pub const fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::NorthEast => (-1, 1),
+            Direction::East => (0, 1),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (1, 0),
+            Direction::SouthWest => (1, -1),
+            Direction::West => (0, -1),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+
+    /// The step of this direction as a vector.
+    pub const fn vector(self) -> Vector {
+        let (row, column) = self.delta();
+        vec2(row, column)
+    }
+
+    pub const fn is_diagonal(self) -> bool {
+        !matches!(
+            self,
+            Direction::North | Direction::East | Direction::South | Direction::West
+        )
+    }
+
+    /// The direction rotated by 90° clockwise.
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// The direction rotated by 90° counterclockwise.
+    #[must_use]
+    pub const fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    #[must_use]
+    pub const fn turn_around(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// The direction rotated clockwise by a number of 45° steps.
+    const fn rotate(self, steps: usize) -> Self {
+        Self::ALL[(self as usize + steps) % 8]
+    }
+
+    /// Parse a cardinal direction from an arrow (`^>v<`), a compass point (`NESW`) or a move (`UDLR`).
+    pub const fn from_char(c: char) -> Option<Self> {
+        match c {
+            '^' | 'N' | 'U' => Some(Direction::North),
+            '>' | 'E' | 'R' => Some(Direction::East),
+            'v' | 'S' | 'D' => Some(Direction::South),
+            '<' | 'W' | 'L' => Some(Direction::West),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Direction::from_char(c).ok_or_else(|| format!("unexpected direction `{c}`"))
+    }
+}
+
+/// Parses a direction like [`Direction::from_char`], or a diagonal compass point like `NE`.
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" => Ok(Direction::NorthEast),
+            "SE" => Ok(Direction::SouthEast),
+            "SW" => Ok(Direction::SouthWest),
+            "NW" => Ok(Direction::NorthWest),
+            _ => {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Direction::try_from(c),
+                    _ => Err(format!("unexpected direction `{s}`")),
+                }
+            }
+        }
+    }
+}
+
+/// Movement and distances of positions.
+pub trait PosExt: Sized {
+    /// The position one step in a direction.
+    #[must_use]
+    fn step(self, direction: Direction) -> Self;
+
+    /// The position one step in a direction, [`None`] if it leaves `bounds`. The maximum of the bounds is exclusive.
+    fn step_within(self, direction: Direction, bounds: &Box2D<isize, isize>) -> Option<Self>;
+
+    /// The number of orthogonal steps between two positions.
+    fn manhattan(self, other: Self) -> isize;
+
+    /// The number of steps between two positions if diagonal steps are allowed as well.
+    fn chebyshev(self, other: Self) -> isize;
+
+    /// The position as `(row, column)` of a [`Grid`](crate::grid::Grid), [`None`] if it is negative.
+    fn to_grid(self) -> Option<(usize, usize)>;
+
+    /// The position of a `(row, column)` of a [`Grid`](crate::grid::Grid).
+    fn from_grid(pos: (usize, usize)) -> Self;
+}
+
+impl PosExt for Pos {
+    fn step(self, direction: Direction) -> Self {
+        self + direction.vector()
+    }
+
+    fn step_within(self, direction: Direction, bounds: &Box2D<isize, isize>) -> Option<Self> {
+        let pos = self.step(direction);
+        bounds.contains(pos).then_some(pos)
+    }
+
+    fn manhattan(self, other: Self) -> isize {
+        let delta = (other - self).abs();
+        delta.x + delta.y
+    }
+
+    fn chebyshev(self, other: Self) -> isize {
+        let delta = (other - self).abs();
+        delta.x.max(delta.y)
+    }
+
+    fn to_grid(self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?))
+    }
+
+    fn from_grid((row, column): (usize, usize)) -> Self {
+        point2(row as isize, column as isize)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use euclid::{point2, Box2D};
+
+    use super::{Direction, Pos, PosExt};
+
+    #[test]
+    fn turns() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::NorthEast.turn_right(), Direction::SouthEast);
+        assert_eq!(Direction::South.turn_around(), Direction::North);
+        assert_eq!(Direction::SouthWest.turn_around(), Direction::NorthEast);
+
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_around().vector(), -direction.vector());
+        }
+    }
+
+    #[test]
+    fn parses_directions() {
+        for (chars, direction) in ["^NU", ">ER", "vSD", "<WL"].iter().zip(Direction::CARDINAL) {
+            for c in chars.chars() {
+                assert_eq!(Direction::try_from(c), Ok(direction));
+            }
+        }
+
+        assert_eq!("NW".parse(), Ok(Direction::NorthWest));
+        assert_eq!("v".parse(), Ok(Direction::South));
+        assert!("x".parse::<Direction>().is_err());
+        assert!("NN".parse::<Direction>().is_err());
+        assert!(!Direction::East.is_diagonal());
+        assert!(Direction::SouthEast.is_diagonal());
+    }
+
+    #[test]
+    fn moves_positions() {
+        let pos: Pos = point2(1, 1);
+        assert_eq!(pos.step(Direction::North), point2(0, 1));
+        assert_eq!(pos.step(Direction::SouthEast), point2(2, 2));
+
+        let bounds = Box2D::new(point2(0, 0), point2(2, 2));
+        assert_eq!(
+            pos.step_within(Direction::West, &bounds),
+            Some(point2(1, 0))
+        );
+        assert_eq!(pos.step_within(Direction::South, &bounds), None);
+
+        assert_eq!(pos.to_grid(), Some((1, 1)));
+        assert_eq!(
+            pos.step(Direction::NorthWest)
+                .step(Direction::North)
+                .to_grid(),
+            None
+        );
+        assert_eq!(Pos::from_grid((3, 4)), point2(3, 4));
+    }
+
+    #[test]
+    fn measures_distances() {
+        let a: Pos = point2(1, -2);
+        let b: Pos = point2(4, 2);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(b.manhattan(a), 7);
+    }
+}
@@ -0,0 +1,506 @@
+/// Shortest paths through a graph given by a successor function, e.g. the open neighbours of a position on a map.
+///
+/// [`bfs`] searches graphs where every step costs the same, [`dijkstra`] weighted graphs and [`astar`] weighted graphs
+/// with a heuristic. Each search runs until the closest goal and every other goal at the same distance is reached, or
+/// until the whole graph is explored if nothing is a goal. It records every shortest way to reach each node, so that
+/// all shortest paths can be followed back, not just one.
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use num::Zero;
+use priority_queue::PriorityQueue;
+
+/// The result of a search: the distance of each reached node and the nodes it is reached from on a shortest path.
+#[derive(Clone, Debug)]
+pub struct ShortestPaths<N, C> {
+    start: N,
+    goals: Vec<N>,
+    distances: HashMap<N, C>,
+    predecessors: HashMap<N, Vec<N>>,
+}
+
+impl<N, C> ShortestPaths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy,
+{
+    pub fn start(&self) -> &N {
+        &self.start
+    }
+
+    /// The goals at the shortest distance from the start, in the order they were reached.
+    pub fn goals(&self) -> &[N] {
+        &self.goals
+    }
+
+    /// The distance of the closest goal, [`None`] if no goal was reached.
+    pub fn goal_distance(&self) -> Option<C> {
+        self.goals.first().and_then(|goal| self.distance(goal))
+    }
+
+    /// The distance of a node from the start, [`None`] if it wasn't reached.
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    /// The distances of every reached node.
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.distances
+    }
+
+    /// The nodes that lead to a node on a shortest path, empty for the start and unreached nodes.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// The predecessors of every reached node but the start.
+    pub fn predecessor_map(&self) -> &HashMap<N, Vec<N>> {
+        &self.predecessors
+    }
+
+    /// A shortest path from the start to the closest goal, both included.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goals.first()?)
+    }
+
+    /// A shortest path from the start to a node, both included. When there are several, the first one found is taken.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// The nodes on any shortest path to the closest goals.
+    pub fn nodes_on_shortest_paths(&self) -> HashSet<N> {
+        self.nodes_on_paths_to(&self.goals)
+    }
+
+    /// The nodes on any shortest path to any of the given nodes.
+    pub fn nodes_on_paths_to<'a>(&'a self, nodes: impl IntoIterator<Item = &'a N>) -> HashSet<N> {
+        let mut stack: Vec<&N> = nodes
+            .into_iter()
+            .filter(|node| self.distances.contains_key(node))
+            .collect();
+        let mut seen = HashSet::new();
+
+        while let Some(node) = stack.pop() {
+            if seen.insert(node.clone()) {
+                stack.extend(self.predecessors(node));
+            }
+        }
+
+        seen
+    }
+
+    /// The number of distinct shortest paths to the closest goals.
+    pub fn count_shortest_paths(&self) -> usize {
+        self.goals
+            .iter()
+            .map(|goal| self.count_paths_to(goal))
+            .sum()
+    }
+
+    /// The number of distinct shortest paths from the start to a node, 0 if it wasn't reached.
+    pub fn count_paths_to(&self, node: &N) -> usize {
+        // NOTE: counted without recursion, paths through a large map are too long for the stack.
+        let mut counts: HashMap<&N, usize> = HashMap::new();
+        let mut stack = vec![node];
+
+        while let Some(&node) = stack.last() {
+            if counts.contains_key(node) {
+                stack.pop();
+                continue;
+            }
+
+            let predecessors = self.predecessors(node);
+            let pending: Vec<&N> = predecessors
+                .iter()
+                .filter(|previous| !counts.contains_key(previous))
+                .collect();
+
+            if pending.is_empty() {
+                let count = if *node == self.start {
+                    1
+                } else {
+                    predecessors.iter().map(|previous| counts[previous]).sum()
+                };
+
+                counts.insert(node, count);
+                stack.pop();
+            } else {
+                stack.extend(pending);
+            }
+        }
+
+        counts[node]
+    }
+}
+
+/// Breadth-first search from `start`, where every step to a successor costs 1.
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> ShortestPaths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut predecessors: HashMap<N, Vec<N>> = HashMap::new();
+    let mut goals = vec![];
+    let mut goal_distance = None;
+    let mut queue = VecDeque::from([start.clone()]);
+
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node];
+
+        if goal_distance.is_some_and(|goal_distance| distance > goal_distance) {
+            break;
+        }
+
+        if is_goal(&node) {
+            goal_distance = Some(distance);
+            goals.push(node);
+            continue;
+        }
+
+        // nodes at the goal's distance are only checked, anything further away can't be on a shortest path.
+        if goal_distance == Some(distance) {
+            continue;
+        }
+
+        for next in successors(&node) {
+            match distances.get(&next) {
+                Some(&known) if known == distance + 1 => {
+                    predecessors.entry(next).or_default().push(node.clone());
+                }
+                Some(_) => {}
+                None => {
+                    distances.insert(next.clone(), distance + 1);
+                    predecessors.insert(next.clone(), vec![node.clone()]);
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+
+    // nodes queued beyond the goal's distance were never reached as far as the search is concerned.
+    if let Some(goal_distance) = goal_distance {
+        distances.retain(|_, distance| *distance <= goal_distance);
+        predecessors.retain(|node, _| distances.contains_key(node));
+    }
+
+    ShortestPaths {
+        start,
+        goals,
+        distances,
+        predecessors,
+    }
+}
+
+/// Dijkstra's algorithm from `start`, where each successor comes with the cost of the step to it. Costs must not be
+/// negative.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> ShortestPaths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::zero(), is_goal)
+}
+
+/// A* search from `start`, where each successor comes with the cost of the step to it. The `heuristic` estimates the
+/// remaining cost to a goal; it must never overestimate it, nor drop by more than the cost of a step, for the
+/// distances to be the shortest.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> ShortestPaths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (N, C)>,
+{
+    // the best known distances, final once a node is taken from the queue.
+    let mut tentative = HashMap::from([(start.clone(), C::zero())]);
+    let mut distances = HashMap::new();
+    let mut predecessors: HashMap<N, Vec<N>> = HashMap::new();
+    let mut goals = vec![];
+    let mut goal_distance = None;
+
+    let mut queue = PriorityQueue::new();
+    queue.push(start.clone(), Reverse(heuristic(&start)));
+
+    while let Some((node, Reverse(estimate))) = queue.pop() {
+        if goal_distance.is_some_and(|goal_distance| estimate > goal_distance) {
+            break;
+        }
+
+        let distance = tentative[&node];
+        distances.insert(node.clone(), distance);
+
+        if is_goal(&node) {
+            goal_distance = Some(distance);
+            goals.push(node);
+            continue;
+        }
+
+        for (next, cost) in successors(&node) {
+            let next_distance = distance + cost;
+
+            match tentative.get(&next) {
+                Some(&known) if next_distance > known => {}
+                Some(&known) if next_distance == known => {
+                    predecessors.entry(next).or_default().push(node.clone());
+                }
+                _ => {
+                    tentative.insert(next.clone(), next_distance);
+                    predecessors.insert(next.clone(), vec![node.clone()]);
+
+                    let estimate = next_distance + heuristic(&next);
+                    queue.push(next, Reverse(estimate));
+                }
+            }
+        }
+    }
+
+    // the predecessors of nodes still in the queue may be incomplete.
+    predecessors.retain(|node, _| distances.contains_key(node));
+
+    ShortestPaths {
+        start,
+        goals,
+        distances,
+        predecessors,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{astar, bfs, dijkstra};
+    use crate::grid::Grid;
+    use crate::pos::{Direction, Pos, PosExt};
+
+    /// The second example of 2024 day 16.
+    const MAZE: &str = "#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################";
+
+    /// The example of 2024 day 20.
+    const RACETRACK: &str = "###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############";
+
+    /// The first 12 bytes of the example of 2024 day 18, on a 7x7 memory space.
+    const BYTES: [(usize, usize); 12] = [
+        (5, 4),
+        (4, 2),
+        (4, 5),
+        (3, 0),
+        (2, 1),
+        (6, 3),
+        (2, 4),
+        (1, 5),
+        (0, 6),
+        (3, 3),
+        (2, 6),
+        (5, 1),
+    ];
+
+    fn open_neighbours(
+        map: &Grid<char>,
+        pos: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        map.neighbours_4(pos).filter(|&next| map[next] != '#')
+    }
+
+    fn memory_space() -> Grid<char> {
+        let mut map = Grid::new(7, 7, '.');
+        for (x, y) in BYTES {
+            map[(y, x)] = '#';
+        }
+        map
+    }
+
+    #[test]
+    fn finds_shortest_paths_with_bfs() {
+        let map = memory_space();
+        let paths = bfs(
+            (0, 0),
+            |&pos| open_neighbours(&map, pos),
+            |&pos| pos == (6, 6),
+        );
+
+        assert_eq!(paths.goal_distance(), Some(22));
+        assert_eq!(paths.goals(), &[(6, 6)]);
+
+        let path = paths.path().unwrap();
+        assert_eq!(path.len(), 23);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert!(path
+            .windows(2)
+            .all(|step| map.neighbours_4(step[0]).any(|pos| pos == step[1])));
+
+        // every node on a shortest path is on the way, and the path found is one of them.
+        let nodes = paths.nodes_on_shortest_paths();
+        assert!(path.iter().all(|pos| nodes.contains(pos)));
+        assert!(paths.count_shortest_paths() >= 1);
+    }
+
+    #[test]
+    fn explores_everything_without_a_goal() {
+        let map = Grid::parse(RACETRACK, |c| c).unwrap();
+        let start = map.find(|c| *c == 'S').unwrap();
+        let end = map.find(|c| *c == 'E').unwrap();
+
+        let paths = bfs(start, |&pos| open_neighbours(&map, pos), |_| false);
+
+        assert!(paths.goals().is_empty());
+        assert_eq!(paths.goal_distance(), None);
+        assert_eq!(paths.distance(&end), Some(84));
+        assert_eq!(paths.distances().len(), 85);
+        assert_eq!(paths.count_paths_to(&end), 1);
+        assert_eq!(paths.path_to(&end).unwrap().len(), 85);
+    }
+
+    #[test]
+    fn stops_without_a_path() {
+        let mut map = memory_space();
+        map[(0, 1)] = '#';
+        map[(1, 0)] = '#';
+
+        let paths = bfs(
+            (0, 0),
+            |&pos| open_neighbours(&map, pos),
+            |&pos| pos == (6, 6),
+        );
+
+        assert_eq!(paths.goal_distance(), None);
+        assert_eq!(paths.path(), None);
+        assert_eq!(paths.distances().len(), 1);
+        assert_eq!(paths.count_paths_to(&(6, 6)), 0);
+        assert!(paths.nodes_on_shortest_paths().is_empty());
+    }
+
+    #[test]
+    fn counts_shortest_paths() {
+        let map = Grid::new(3, 3, '.');
+        let paths = bfs(
+            (0, 0),
+            |&pos| open_neighbours(&map, pos),
+            |&pos| pos == (2, 2),
+        );
+
+        assert_eq!(paths.goal_distance(), Some(4));
+        assert_eq!(paths.count_shortest_paths(), 6);
+        assert_eq!(paths.count_paths_to(&(1, 1)), 2);
+        assert_eq!(paths.predecessors(&(1, 1)).len(), 2);
+        assert!(paths.predecessors(&(0, 0)).is_empty());
+        assert_eq!(paths.nodes_on_shortest_paths().len(), 9);
+    }
+
+    #[test]
+    fn finds_every_best_path_with_dijkstra() {
+        let map = Grid::parse(MAZE, |c| c).unwrap();
+        let start = Pos::from_grid(map.find(|c| *c == 'S').unwrap());
+        let end = Pos::from_grid(map.find(|c| *c == 'E').unwrap());
+        let is_open = |pos: Pos| pos.to_grid().and_then(|pos| map.get(pos)) != Some(&'#');
+
+        let paths = dijkstra(
+            (start, Direction::East),
+            |&(pos, direction)| {
+                [
+                    (direction, 1),
+                    (direction.turn_left(), 1001),
+                    (direction.turn_right(), 1001),
+                ]
+                .into_iter()
+                .map(move |(direction, cost)| ((pos.step(direction), direction), cost))
+                .filter(|((pos, _), _)| is_open(*pos))
+            },
+            |&(pos, _)| pos == end,
+        );
+
+        assert_eq!(paths.goal_distance(), Some(11048));
+
+        let tiles: HashSet<Pos> = paths
+            .nodes_on_shortest_paths()
+            .into_iter()
+            .map(|(pos, _)| pos)
+            .collect();
+        assert_eq!(tiles.len(), 64);
+    }
+
+    #[test]
+    fn agrees_with_dijkstra_using_astar() {
+        let map = memory_space();
+        let goal = (6, 6);
+        let successors = |&pos: &(usize, usize)| open_neighbours(&map, pos).map(|next| (next, 1));
+
+        let with_dijkstra = dijkstra((0, 0), successors, |&pos| pos == goal);
+        let with_astar = astar(
+            (0, 0),
+            successors,
+            |&pos| Pos::from_grid(pos).manhattan(Pos::from_grid(goal)) as usize,
+            |&pos| pos == goal,
+        );
+
+        assert_eq!(with_astar.goal_distance(), Some(22));
+        assert_eq!(
+            with_astar.count_shortest_paths(),
+            with_dijkstra.count_shortest_paths()
+        );
+        assert_eq!(
+            with_astar.nodes_on_shortest_paths(),
+            with_dijkstra.nodes_on_shortest_paths()
+        );
+        // the heuristic keeps A* from exploring away from the goal.
+        assert!(with_astar.distances().len() <= with_dijkstra.distances().len());
+    }
+}
@@ -0,0 +1,167 @@
+/// Counts the allocations of solutions, so that the runner can report how much memory each part needs.
+///
+/// With the `alloc-stats` feature, [`CountingAlloc`] is registered as the global allocator. It forwards to the system
+/// allocator and keeps a count of allocations, allocated bytes and live bytes. Unlike profiling with `--dhat`, this
+/// works in normal runs, both in-process and isolated, at the cost of a few atomic operations per allocation.
+///
+/// The counters are shared by all threads, so allocations of other threads while a part runs are counted as well.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+use crate::template::timings::AllocStats;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// NOTE: solution binaries register dhat's allocator when profiling with dhat, it takes precedence.
+#[cfg(all(feature = "alloc-stats", not(feature = "dhat-heap")))]
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+/// Whether allocations are counted.
+pub const fn is_enabled() -> bool {
+    cfg!(all(feature = "alloc-stats", not(feature = "dhat-heap")))
+}
+
+/// A global allocator that counts the allocations of the system allocator.
+pub struct CountingAlloc;
+
+impl CountingAlloc {
+    fn record_alloc(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(size as u64, Ordering::Relaxed);
+        let live = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK_BYTES.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn record_dealloc(size: usize) {
+        LIVE_BYTES.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::record_dealloc(layout.size());
+    }
+
+    /// A reallocation counts as an allocation of the new size, replacing the old one.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::record_dealloc(layout.size());
+            Self::record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Run a function and count its allocations. Returns [`None`] instead of the counts if allocations are not counted.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if is_enabled() {
+        let (result, stats) = count(func);
+        (result, Some(stats))
+    } else {
+        (func(), None)
+    }
+}
+
+/// Count the allocations of a function. The peak is the most memory it held at once, on top of what was live before.
+fn count<T>(func: impl FnOnce() -> T) -> (T, AllocStats) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let live_bytes = LIVE_BYTES.load(Ordering::Relaxed);
+    PEAK_BYTES.store(live_bytes, Ordering::Relaxed);
+
+    let result = func();
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak_bytes: PEAK_BYTES
+            .load(Ordering::Relaxed)
+            .saturating_sub(live_bytes) as u64,
+    };
+
+    (result, stats)
+}
+
+/// Format a number of bytes in binary units, e.g. `1.5 KiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_bytes, CountingAlloc};
+    use std::alloc::{GlobalAlloc, Layout};
+
+    // NOTE: with the allocator registered, other tests running in parallel would be counted as well.
+    #[cfg(not(feature = "alloc-stats"))]
+    #[test]
+    fn counts_allocations() {
+        use crate::template::timings::AllocStats;
+
+        let (_, stats) = super::count(|| unsafe {
+            let layout = Layout::from_size_align(64, 8).unwrap();
+            let ptr = CountingAlloc.alloc(layout);
+            let ptr = CountingAlloc.realloc(ptr, layout, 128);
+            CountingAlloc.dealloc(ptr, Layout::from_size_align(128, 8).unwrap());
+
+            let layout = Layout::from_size_align(32, 8).unwrap();
+            let ptr = CountingAlloc.alloc_zeroed(layout);
+            CountingAlloc.dealloc(ptr, layout);
+        });
+
+        assert_eq!(
+            stats,
+            AllocStats {
+                allocations: 3,
+                bytes: 224,
+                peak_bytes: 128,
+            }
+        );
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
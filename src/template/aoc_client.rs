@@ -0,0 +1,365 @@
+/// Client for the Advent of Code website.
+///
+/// Authenticates with the `session` cookie of a logged-in browser session, which is read from the session source of
+/// `aoc.toml`. By default, that's the `AOC_SESSION` environment variable or `~/.adventofcode.session`.
+use std::{env, fmt::Display, io};
+
+use crate::template::config::config;
+use crate::template::html_to_markdown::{extract_articles, html_to_markdown};
+use crate::template::Puzzle;
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    Unauthenticated,
+    NotUnlocked,
+    RateLimited(String),
+    BadStatus(u16),
+    UnexpectedResponse,
+    Transport(String),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. To configure it, {}.",
+                config().session
+            ),
+            AocClientError::Unauthenticated => {
+                write!(
+                    f,
+                    "not logged in. Your session cookie is missing or expired."
+                )
+            }
+            AocClientError::NotUnlocked => write!(f, "this puzzle has not been unlocked yet."),
+            AocClientError::RateLimited(message) => write!(f, "rate limited. {message}"),
+            AocClientError::BadStatus(status) => {
+                write!(f, "adventofcode.com responded with status {status}.")
+            }
+            AocClientError::UnexpectedResponse => {
+                write!(f, "could not understand the response of adventofcode.com.")
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').into(),
+            session,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Build a client from the environment. `AOC_BASE_URL` overrides the website, e.g. to point at a mock server.
+    pub fn from_env() -> Self {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Self::new(&base_url, read_session())
+    }
+
+    /// Fetch the input of a puzzle.
+    pub fn input(&self, puzzle: Puzzle) -> Result<String, AocClientError> {
+        self.require_session()?;
+        self.get(&format!("{}/input", get_puzzle_path(puzzle)))
+    }
+
+    /// Fetch the description of a puzzle, converted to markdown.
+    /// Part two is only included once part one was solved.
+    pub fn puzzle(&self, puzzle: Puzzle) -> Result<String, AocClientError> {
+        let html = self.get(&get_puzzle_path(puzzle))?;
+
+        let articles = extract_articles(&html);
+        if articles.is_empty() {
+            return Err(AocClientError::UnexpectedResponse);
+        }
+
+        Ok(articles
+            .into_iter()
+            .map(html_to_markdown)
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+
+    /// Submit an answer and return the website's reply as text.
+    pub fn submit(&self, puzzle: Puzzle, part: u8, answer: &str) -> Result<String, AocClientError> {
+        self.require_session()?;
+
+        let url = format!("{}{}/answer", self.base_url, get_puzzle_path(puzzle));
+        let part = part.to_string();
+        let response = self
+            .authenticate(self.agent.post(&url))
+            .send_form(&[("level", &part), ("answer", answer)]);
+
+        let html = read_response(response)?;
+
+        let Some(article) = extract_articles(&html).into_iter().next() else {
+            return Err(AocClientError::UnexpectedResponse);
+        };
+
+        let message = html_to_markdown(article).trim().to_string();
+
+        if message.contains("You gave an answer too recently") {
+            Err(AocClientError::RateLimited(message))
+        } else if message.contains("To play, please identify yourself") {
+            Err(AocClientError::Unauthenticated)
+        } else {
+            Ok(message)
+        }
+    }
+
+    fn get(&self, path: &str) -> Result<String, AocClientError> {
+        let url = format!("{}{path}", self.base_url);
+        read_response(self.authenticate(self.agent.get(&url)).call())
+    }
+
+    fn authenticate(&self, request: ureq::Request) -> ureq::Request {
+        match &self.session {
+            Some(session) => request.set("Cookie", &format!("session={session}")),
+            None => request,
+        }
+    }
+
+    fn require_session(&self) -> Result<(), AocClientError> {
+        match self.session {
+            Some(_) => Ok(()),
+            None => Err(AocClientError::SessionNotFound),
+        }
+    }
+}
+
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String, AocClientError> {
+    match response {
+        Ok(response) => Ok(response.into_string()?),
+        Err(ureq::Error::Status(status, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            Err(classify_error(status, &body))
+        }
+        Err(ureq::Error::Transport(e)) => Err(AocClientError::Transport(e.to_string())),
+    }
+}
+
+fn classify_error(status: u16, body: &str) -> AocClientError {
+    if status == 404 {
+        AocClientError::NotUnlocked
+    } else if status == 429 {
+        AocClientError::RateLimited(body.trim().into())
+    } else if body.contains("log in") || body.contains("identify yourself") {
+        AocClientError::Unauthenticated
+    } else {
+        AocClientError::BadStatus(status)
+    }
+}
+
+/// Read the session cookie from the session source of `aoc.toml`.
+fn read_session() -> Option<String> {
+    parse_session(&config().session.read()?)
+}
+
+/// Accepts either the bare cookie value or a `session=<value>` pair.
+fn parse_session(session: &str) -> Option<String> {
+    let session = session.trim();
+    let session = session.strip_prefix("session=").unwrap_or(session);
+
+    if session.is_empty() {
+        None
+    } else {
+        Some(session.into())
+    }
+}
+
+/// Returns the path of a puzzle on the website, e.g. `/2024/day/1`.
+fn get_puzzle_path(puzzle: Puzzle) -> String {
+    format!("/{}/day/{}", puzzle.year, puzzle.day.into_inner())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    use super::{parse_session, AocClient, AocClientError};
+    use crate::puzzle;
+
+    /// Serve one canned response per connection and return the raw requests that were received.
+    fn mock_server(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request = String::new();
+                let mut content_length = 0;
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = len.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                requests.push(request);
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+
+            requests
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn downloads_input() {
+        let (base_url, server) = mock_server(vec![(200, "3   4\n4   3\n")]);
+        let client = AocClient::new(&base_url, Some("abc".into()));
+
+        assert_eq!(client.input(puzzle!(2024, 1)).unwrap(), "3   4\n4   3\n");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2024/day/1/input "));
+        assert!(requests[0].contains("session=abc"));
+    }
+
+    #[test]
+    fn downloads_puzzle_as_markdown() {
+        let (base_url, server) = mock_server(vec![(
+            200,
+            r#"<main><article class="day-desc"><h2>--- Day 1 ---</h2><p>Part <em>one</em>.</p></article><p>Answer: <code>11</code></p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2></article></main>"#,
+        )]);
+        let client = AocClient::new(&base_url, None);
+
+        assert_eq!(
+            client.puzzle(puzzle!(2024, 1)).unwrap(),
+            "## --- Day 1 ---\n\nPart *one*.\n\n## --- Part Two ---\n"
+        );
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (base_url, server) = mock_server(vec![(
+            200,
+            "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>",
+        )]);
+        let client = AocClient::new(&base_url, Some("abc".into()));
+
+        let message = client.submit(puzzle!(2024, 5), 2, "4130").unwrap();
+        assert!(message.starts_with("That's the right answer!"));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2024/day/5/answer "));
+        assert!(requests[0].ends_with("level=2&answer=4130"));
+    }
+
+    #[test]
+    fn handles_rate_limiting() {
+        let (base_url, server) = mock_server(vec![(
+            200,
+            "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 37s left to wait.</p></article>",
+        )]);
+        let client = AocClient::new(&base_url, Some("abc".into()));
+
+        assert!(matches!(
+            client.submit(puzzle!(2024, 5), 1, "1"),
+            Err(AocClientError::RateLimited(message)) if message.contains("37s left")
+        ));
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn handles_error_responses() {
+        let (base_url, server) = mock_server(vec![
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+            (500, "Internal Server Error"),
+        ]);
+        let client = AocClient::new(&base_url, Some("abc".into()));
+
+        assert!(matches!(
+            client.input(puzzle!(2024, 25)),
+            Err(AocClientError::NotUnlocked)
+        ));
+        assert!(matches!(
+            client.input(puzzle!(2024, 1)),
+            Err(AocClientError::Unauthenticated)
+        ));
+        assert!(matches!(
+            client.input(puzzle!(2024, 1)),
+            Err(AocClientError::BadStatus(500))
+        ));
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn requires_session() {
+        let client = AocClient::new("http://127.0.0.1:1", None);
+        assert!(matches!(
+            client.input(puzzle!(2024, 1)),
+            Err(AocClientError::SessionNotFound)
+        ));
+        assert!(matches!(
+            client.submit(puzzle!(2024, 1), 1, "1"),
+            Err(AocClientError::SessionNotFound)
+        ));
+    }
+
+    #[test]
+    fn parses_session() {
+        assert_eq!(parse_session("abc\n"), Some("abc".into()));
+        assert_eq!(parse_session("session=abc"), Some("abc".into()));
+        assert_eq!(parse_session("  \n"), None);
+    }
+}
@@ -0,0 +1,141 @@
+/// Time budgets for solution parts, so that one slow day can't hold up a run of several days.
+///
+/// A budget is set for all days with `--budget <duration>` and overridden for single days with
+/// `--budget <day>=<duration>`, e.g. `--budget 10s --budget 6=1m`.
+use std::{collections::HashMap, str::FromStr, time::Duration};
+
+use crate::template::Day;
+
+/// The time budgets of a run. Parts of days without a budget may run indefinitely.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Budgets {
+    /// Applies to every day without an override.
+    pub default: Option<Duration>,
+    pub days: HashMap<Day, Duration>,
+}
+
+impl Budgets {
+    /// The budget of each part of a day, [`None`] if its parts may run indefinitely.
+    pub fn for_day(&self, day: Day) -> Option<Duration> {
+        self.days.get(&day).copied().or(self.default)
+    }
+}
+
+impl Extend<BudgetSpec> for Budgets {
+    /// Later specs override earlier ones, e.g. `--budget` arguments override the budgets of `aoc.toml`.
+    fn extend<T: IntoIterator<Item = BudgetSpec>>(&mut self, iter: T) {
+        for spec in iter {
+            match spec {
+                BudgetSpec::Default(budget) => self.default = Some(budget),
+                BudgetSpec::Day(day, budget) => {
+                    self.days.insert(day, budget);
+                }
+            }
+        }
+    }
+}
+
+impl FromIterator<BudgetSpec> for Budgets {
+    fn from_iter<T: IntoIterator<Item = BudgetSpec>>(iter: T) -> Self {
+        let mut budgets = Budgets::default();
+        budgets.extend(iter);
+        budgets
+    }
+}
+
+/// A single `--budget` argument.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BudgetSpec {
+    /// `<duration>`, the budget of all days.
+    Default(Duration),
+    /// `<day>=<duration>`, the budget of a single day.
+    Day(Day, Duration),
+}
+
+impl FromStr for BudgetSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('=') {
+            Some((day, budget)) => {
+                let day = day
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("invalid day `{day}` in budget `{s}`"))?;
+                Ok(BudgetSpec::Day(day, parse_budget(budget)?))
+            }
+            None => Ok(BudgetSpec::Default(parse_budget(s)?)),
+        }
+    }
+}
+
+/// Parse a duration like `500ms`, `10s` or `2m`. Plain numbers are seconds.
+fn parse_budget(s: &str) -> Result<Duration, String> {
+    let s = s.trim();
+    let unit_start = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (value, unit) = s.split_at(unit_start);
+
+    let factor = match unit {
+        "ms" => 0.001,
+        "" | "s" => 1.0,
+        "m" => 60.0,
+        _ => {
+            return Err(format!(
+                "unknown unit `{unit}` in budget `{s}`, expecting ms, s or m"
+            ))
+        }
+    };
+
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|value| *value > 0.0)
+        .map(|value| Duration::from_secs_f64(value * factor))
+        .ok_or(format!(
+            "invalid budget `{s}`, expecting a positive duration"
+        ))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{BudgetSpec, Budgets};
+    use crate::day;
+
+    #[test]
+    fn parses_budgets() {
+        assert_eq!(
+            "10s".parse::<BudgetSpec>(),
+            Ok(BudgetSpec::Default(Duration::from_secs(10)))
+        );
+        assert_eq!(
+            "2".parse::<BudgetSpec>(),
+            Ok(BudgetSpec::Default(Duration::from_secs(2)))
+        );
+        assert_eq!(
+            "06=500ms".parse::<BudgetSpec>(),
+            Ok(BudgetSpec::Day(day!(6), Duration::from_millis(500)))
+        );
+        assert_eq!(
+            "9=1.5m".parse::<BudgetSpec>(),
+            Ok(BudgetSpec::Day(day!(9), Duration::from_secs(90)))
+        );
+        assert!("10h".parse::<BudgetSpec>().is_err());
+        assert!("0s".parse::<BudgetSpec>().is_err());
+        assert!("26=10s".parse::<BudgetSpec>().is_err());
+    }
+
+    #[test]
+    fn overrides_budgets_per_day() {
+        let budgets: Budgets = ["10s", "6=1m"].iter().map(|s| s.parse().unwrap()).collect();
+
+        assert_eq!(budgets.for_day(day!(1)), Some(Duration::from_secs(10)));
+        assert_eq!(budgets.for_day(day!(6)), Some(Duration::from_secs(60)));
+        assert_eq!(Budgets::default().for_day(day!(6)), None);
+    }
+}
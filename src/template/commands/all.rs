@@ -1,5 +1,20 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::process;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::budgets::Budgets;
+use crate::template::run_multi::{run_multi, MultiRunOptions};
+use crate::template::{all_days, Year};
+
+/// Run all solutions of a year, stopping parts that exceed their budget.
+/// Exits with a non-zero status if any of them failed.
+pub fn handle(year: Year, is_release: bool, is_isolated: bool, budgets: &Budgets) {
+    let options = MultiRunOptions {
+        is_release,
+        is_timed: false,
+        is_isolated,
+    };
+    let run = run_multi(year, &all_days(year).collect(), options, budgets);
+
+    if run.has_failures() {
+        process::exit(1);
+    }
 }
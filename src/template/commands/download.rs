@@ -1,14 +1,44 @@
-use crate::template::{aoc_cli, Day};
-use std::process;
+use crate::template::aoc_client::AocClient;
+use crate::template::{data_file_path, Puzzle};
+use std::{fs, path::Path, process};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+pub fn handle(puzzle: Puzzle) {
+    let client = AocClient::from_env();
+
+    let input_path = data_file_path("inputs", puzzle);
+    let puzzle_path = data_file_path("puzzles", puzzle).with_extension("md");
+
+    let input = client.input(puzzle).unwrap_or_else(|e| {
+        eprintln!("Failed to download input: {e}");
         process::exit(1);
-    }
+    });
+
+    let description = client.puzzle(puzzle).unwrap_or_else(|e| {
+        eprintln!("Failed to download puzzle: {e}");
+        process::exit(1);
+    });
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    write_file(&input_path, &input);
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+
+    write_file(&puzzle_path, &description);
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+}
+
+fn write_file(path: &Path, contents: &str) {
+    let result = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(path, contents));
+
+    if let Err(e) = result {
+        eprintln!("Failed to write \"{}\": {e}", path.display());
         process::exit(1);
-    };
+    }
 }
@@ -1,15 +1,28 @@
-use std::process;
+use std::{fs, process};
 
-use crate::template::{aoc_cli, Day};
+use crate::template::aoc_client::AocClient;
+use crate::template::{data_file_path, Puzzle};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+pub fn handle(puzzle: Puzzle) {
+    let client = AocClient::from_env();
+
+    let description = client.puzzle(puzzle).unwrap_or_else(|e| {
+        eprintln!("Failed to read puzzle: {e}");
         process::exit(1);
+    });
+
+    let puzzle_path = data_file_path("puzzles", puzzle).with_extension("md");
+    let result = puzzle_path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(&puzzle_path, &description));
+
+    if let Err(e) = result {
+        eprintln!(
+            "Failed to write puzzle to \"{}\": {e}",
+            puzzle_path.display()
+        );
     }
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+    println!("{description}");
 }
@@ -1,9 +1,27 @@
-use std::process::{Command, Stdio};
+use std::{
+    path,
+    process::{self, Command, Stdio},
+};
 
-use crate::template::Day;
+use crate::template::allocations;
+use crate::template::answers::Answers;
+use crate::template::config::config;
+use crate::template::named_inputs::{
+    print_heading, print_table, read_named_inputs, run_named_inputs, InputRun,
+};
+use crate::template::registry::get_solution;
+use crate::template::run_multi::child_commands;
+use crate::template::solution_args::{InputSource, SolutionArgs};
+use crate::template::{data_file_path, Puzzle};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+pub fn handle(
+    puzzle: Puzzle,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    mut solution_args: SolutionArgs,
+) {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.to_string()];
 
     if dhat {
         cmd_args.extend([
@@ -12,8 +30,14 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
             "--features".to_string(),
             "dhat-heap".to_string(),
         ]);
-    } else if release {
-        cmd_args.push("--release".to_string());
+    } else {
+        if release {
+            cmd_args.push("--release".to_string());
+        }
+
+        if allocations::is_enabled() {
+            cmd_args.extend(["--features".to_string(), "alloc-stats".to_string()]);
+        }
     }
 
     cmd_args.push("--".to_string());
@@ -23,12 +47,87 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    // the solution runs in the root of the project, resolve input files against the current directory.
+    if let InputSource::File(path) = &mut solution_args.input {
+        match path::absolute(&*path) {
+            Ok(absolute) => *path = absolute,
+            Err(e) => {
+                eprintln!("Failed to resolve input file: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    cmd_args.extend(solution_args.to_args());
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
+        .current_dir(&config().root)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .spawn()
         .unwrap();
 
-    cmd.wait().unwrap();
+    // pass on the exit status, e.g. if a part of the solution failed.
+    let status = cmd.wait().unwrap();
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
+}
+
+/// Run the solution of a puzzle against its puzzle input and each of its named inputs, then print a table of
+/// their answers. Each input runs in a child process of its own, built in release mode if `release` is set, with
+/// the budget of the day from `aoc.toml`.
+/// Exits with a non-zero status if the solution failed or gave a wrong answer for any input.
+pub fn handle_all_inputs(puzzle: Puzzle, release: bool) {
+    if get_solution(puzzle).is_none() {
+        eprintln!(
+            "No solution for day {} uses the `solution!` macro.",
+            puzzle.day
+        );
+        process::exit(1);
+    }
+
+    let inputs = read_named_inputs(puzzle).unwrap_or_else(|e| {
+        eprintln!("Could not read the inputs: {e}");
+        process::exit(1);
+    });
+
+    let answers = Answers::read_from_file(puzzle.year).unwrap_or_else(|e| {
+        eprintln!("Could not read the answers: {e}");
+        process::exit(1);
+    });
+
+    let budget = config().budgets.for_day(puzzle.day);
+    let mut runs = vec![];
+
+    if data_file_path("inputs", puzzle).exists() {
+        print_heading(puzzle, "puzzle");
+
+        let report = child_commands::run_input(
+            puzzle,
+            &InputSource::Puzzle,
+            release,
+            budget,
+            Some(answers.clone()),
+        )
+        .unwrap_or_else(|e| {
+            eprintln!("Failed to run the solution: {e:?}");
+            process::exit(1);
+        });
+        runs.push(InputRun::puzzle(report, &answers));
+    }
+
+    runs.extend(run_named_inputs(puzzle, inputs, release, budget));
+
+    if runs.is_empty() {
+        eprintln!("Day {} has no inputs.", puzzle.day);
+        process::exit(1);
+    }
+
+    print_table(puzzle, &runs);
+
+    if runs.iter().any(InputRun::is_failed) {
+        process::exit(1);
+    }
 }
@@ -1,19 +1,40 @@
 use std::collections::HashSet;
+use std::process;
 
-use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::budgets::Budgets;
+use crate::template::compare::{self, Baseline};
+use crate::template::registry::get_solution;
+use crate::template::run_multi::{run_multi, MultiRunOptions};
+use crate::template::timings::{RunInfo, Timings};
+use crate::template::variants::{has_disagreements, print_tables, run_variants};
+use crate::template::{all_days, readme_benchmarks, try_read_file, Day, Puzzle, Year};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+/// Time solutions and optionally store the timings.
+/// With `compare`, each benched part is compared against a stored baseline. If a `threshold` is set as well,
+/// exits with a non-zero status if any part regressed by more than `threshold` percent.
+/// Exits with a non-zero status as well if any solution failed, after storing the timings of the others.
+/// Parts that exceed their budget are stopped and stored as timed out.
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: Year,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    is_isolated: bool,
+    compare: Option<Baseline>,
+    threshold: Option<f64>,
+    budgets: &Budgets,
+) {
+    let stored_timings = Timings::read_from_file(year);
 
     let days_to_run = day.map_or_else(
         || {
-            if run_all {
-                all_days().collect()
+            // comparing only makes sense for days that were benched before, so run all of them.
+            if run_all || compare.is_some() {
+                all_days(year).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
+                all_days(year)
                     .filter(|day| !stored_timings.is_day_complete(*day))
                     .collect()
             }
@@ -21,14 +42,37 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let options = MultiRunOptions {
+        is_release: true,
+        is_timed: true,
+        is_isolated,
+    };
+    let run = run_multi(year, &days_to_run, options, budgets);
+    let has_failures = run.has_failures();
+    let mut timings = run.timings;
+
+    let run = RunInfo::current();
+    for timing in &mut timings.data {
+        timing.run = run.clone();
+    }
+
+    let mut is_above_threshold = false;
+
+    if let Some(baseline) = compare {
+        let comparisons = compare::compare(&stored_timings, &timings, baseline);
+        compare::print_comparisons(&comparisons, baseline);
+
+        if let Some(threshold) = threshold {
+            is_above_threshold = compare::exceeds_threshold(&comparisons, threshold);
+        }
+    }
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings.store_file(year).unwrap();
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(year, merged_timings) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
@@ -37,4 +81,41 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    if is_above_threshold {
+        eprintln!(
+            "Regression above the threshold of {}%.",
+            threshold.unwrap_or_default()
+        );
+        process::exit(1);
+    }
+
+    if has_failures {
+        process::exit(1);
+    }
+}
+
+/// Bench the variants of each part of a solution against the puzzle input and print their relative speed.
+/// Exits with a non-zero status if a variant disagrees with the solution's own implementation of its part.
+pub fn handle_variants(puzzle: Puzzle, budgets: &Budgets) {
+    let Some(solution) = get_solution(puzzle) else {
+        eprintln!(
+            "No solution for day {} uses the `solution!` macro.",
+            puzzle.day
+        );
+        process::exit(1);
+    };
+
+    let input = try_read_file("inputs", puzzle).unwrap_or_else(|e| {
+        eprintln!("Could not read the input of day {}: {e}", puzzle.day);
+        process::exit(1);
+    });
+    let runs = run_variants(solution, &input, budgets.for_day(puzzle.day));
+    print_tables(puzzle, &runs);
+
+    if has_disagreements(&runs) {
+        eprintln!();
+        eprintln!("The variants of day {} disagree.", puzzle.day);
+        process::exit(1);
+    }
 }
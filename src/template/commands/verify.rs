@@ -0,0 +1,173 @@
+use std::process;
+
+use crate::template::answers::{AnswerCheck, Answers};
+use crate::template::budgets::Budgets;
+use crate::template::named_inputs::{print_table, read_named_inputs, run_named_inputs, InputRun};
+use crate::template::registry::get_solution;
+use crate::template::run_multi::{run_days, MultiRunOptions};
+use crate::template::runner::{PartReport, SolutionReport};
+use crate::template::{all_days, Day, Puzzle, Year, ANSI_BOLD, ANSI_RESET};
+
+/// Run all solutions of a year and check their answers against the answer store in `data/<year>/answers.json`.
+/// Exits with a non-zero status if any answer does not match, or if a solution failed or exceeded its budget,
+/// since its answers can't be verified then. With `store`, answers of parts that have no known-correct answer yet
+/// are recorded in the store. Parts that exceed their budget are stopped.
+///
+/// Days with [named inputs](crate::template::named_inputs) are also run against each of them, their answers are
+/// checked against the expected ones and shown in a table per day.
+pub fn handle(year: Year, store: bool, budgets: &Budgets) {
+    let mut answers = Answers::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("Could not read the answers: {e}");
+        process::exit(1);
+    });
+    let known_answers = answers.clone();
+    // NOTE: checking answers doesn't need timings, so the parts run once, in-process.
+    let results = run_days(
+        year,
+        &all_days(year).collect(),
+        MultiRunOptions::default(),
+        budgets,
+    );
+
+    let mut correct = 0;
+    let mut unknown = 0;
+    let mut mismatches = vec![];
+    let mut failures = vec![];
+    let mut stored = 0;
+
+    for (day, report) in &results {
+        failures.extend(failures_of(*day, "", report));
+    }
+
+    for report in results
+        .iter()
+        .flat_map(|(_, report)| &report.parts)
+        .filter(|report| is_finished(report))
+    {
+        match answers.check(report.puzzle.day, report.part, report.answer.as_deref()) {
+            AnswerCheck::Correct => correct += 1,
+            AnswerCheck::Incorrect { expected } => mismatches.push((report, expected)),
+            AnswerCheck::Unknown => {
+                unknown += 1;
+
+                if let (true, Some(answer)) = (store, &report.answer) {
+                    answers.set(report.puzzle.day, report.part, answer);
+                    stored += 1;
+                }
+            }
+        }
+    }
+
+    let mut mismatches: Vec<_> = mismatches
+        .into_iter()
+        .map(|(report, expected)| (String::new(), report.clone(), expected))
+        .collect();
+
+    for puzzle in all_days(year).filter_map(|day| Puzzle::new(year, day)) {
+        let day = puzzle.day;
+
+        let inputs = match read_named_inputs(puzzle) {
+            Ok(inputs) => inputs,
+            Err(e) => {
+                eprintln!("Could not read the inputs of day {day}: {e}");
+                process::exit(1);
+            }
+        };
+
+        if inputs.is_empty() || get_solution(puzzle).is_none() {
+            continue;
+        }
+
+        let named_runs = run_named_inputs(puzzle, inputs, false, budgets.for_day(day));
+
+        for run in &named_runs {
+            let input = format!(" ({})", run.name);
+            failures.extend(failures_of(day, &input, &run.report));
+
+            for report in run.report.parts.iter().filter(|report| is_finished(report)) {
+                match run.check(report) {
+                    AnswerCheck::Correct => correct += 1,
+                    AnswerCheck::Incorrect { expected } => {
+                        mismatches.push((input.clone(), report.clone(), expected));
+                    }
+                    AnswerCheck::Unknown => unknown += 1,
+                }
+            }
+        }
+
+        let puzzle_run = results
+            .iter()
+            .find(|(result_day, _)| *result_day == day)
+            .map(|(_, report)| InputRun::puzzle(report.clone(), &known_answers));
+
+        let runs: Vec<_> = puzzle_run.into_iter().chain(named_runs).collect();
+        print_table(puzzle, &runs);
+    }
+
+    println!();
+    println!(
+        "{ANSI_BOLD}Verified:{ANSI_RESET} {correct} correct, {} incorrect, {} failed, {unknown} unknown",
+        mismatches.len(),
+        failures.len()
+    );
+
+    for (input, report, expected) in &mismatches {
+        println!(
+            "✘ Day {} Part {}{input}: expected {expected}, got {}",
+            report.puzzle.day,
+            report.part,
+            report.answer.as_deref().unwrap_or("nothing")
+        );
+    }
+
+    for failure in &failures {
+        println!("✘ {failure}");
+    }
+
+    if stored > 0 {
+        match answers.store_file(year) {
+            Ok(()) => println!("Stored {stored} new answer(s)."),
+            Err(e) => {
+                eprintln!("Failed to store answers: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    if !mismatches.is_empty() || !failures.is_empty() {
+        process::exit(1);
+    }
+}
+
+/// Whether a part ran to completion, so that its answer can be checked.
+fn is_finished(report: &PartReport) -> bool {
+    report.failure.is_none() && !report.timed_out
+}
+
+/// The stages of a solution that failed or exceeded their budget, e.g. `Day 3 Part 1 (alice): panicked at ...`.
+fn failures_of(day: Day, input: &str, report: &SolutionReport) -> Vec<String> {
+    let mut failures = vec![];
+
+    if let Some(failure) = &report.failure {
+        failures.push(format!("Day {day}{input}: {failure}"));
+    }
+
+    let parse = report
+        .parse
+        .iter()
+        .map(|parse| ("Parse".to_string(), &parse.failure, parse.timed_out));
+    let parts = report
+        .parts
+        .iter()
+        .map(|part| (format!("Part {}", part.part), &part.failure, part.timed_out));
+
+    for (stage, failure, timed_out) in parse.chain(parts) {
+        match failure {
+            Some(failure) => failures.push(format!("Day {day} {stage}{input}: {failure}")),
+            None if timed_out => failures.push(format!("Day {day} {stage}{input}: timed out")),
+            None => {}
+        }
+    }
+
+    failures
+}
@@ -0,0 +1,277 @@
+/// Compares the timings of a run against stored ones to detect regressions.
+use std::{fmt::Display, str::FromStr, time::Duration};
+
+use crate::template::timings::{BenchStats, Timing, Timings};
+use crate::template::{Day, ANSI_BOLD, ANSI_RESET};
+
+/// Critical value of a two-sided Welch's t-test at 99% confidence.
+/// Parts are sampled at least 10 times, so the normal approximation is used instead of the t-distribution.
+const CRITICAL_T: f64 = 2.576;
+
+/// The stored run a new run is compared against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Baseline {
+    /// The latest stored timing of a day.
+    Previous,
+    /// The fastest stored timing of a part, across the whole history.
+    Best,
+}
+
+impl FromStr for Baseline {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "previous" => Ok(Baseline::Previous),
+            "best" => Ok(Baseline::Best),
+            _ => Err(format!(
+                "unknown baseline `{s}`, expecting `previous` or `best`"
+            )),
+        }
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Baseline::Previous => f.write_str("previous"),
+            Baseline::Best => f.write_str("best"),
+        }
+    }
+}
+
+/// The timing of a part compared against its baseline.
+#[derive(Clone, Debug)]
+pub struct PartComparison {
+    pub day: Day,
+    pub part: u8,
+    pub baseline: BenchStats,
+    pub current: BenchStats,
+}
+
+impl PartComparison {
+    /// Relative change of the mean duration, in percent.
+    pub fn delta_percent(&self) -> f64 {
+        (self.current.mean_nanos - self.baseline.mean_nanos) / self.baseline.mean_nanos * 100.0
+    }
+
+    /// Whether the part got slower by a statistically significant amount.
+    pub fn is_regression(&self) -> bool {
+        self.delta_percent() > 0.0 && self.is_significant()
+    }
+
+    /// Whether the part got faster by a statistically significant amount.
+    pub fn is_improvement(&self) -> bool {
+        self.delta_percent() < 0.0 && self.is_significant()
+    }
+
+    /// Welch's t-test on the sampled means, which does not assume equal variances.
+    fn is_significant(&self) -> bool {
+        let variance = |stats: &BenchStats| stats.std_dev_nanos.powi(2) / stats.samples as f64;
+        let standard_error = (variance(&self.baseline) + variance(&self.current)).sqrt();
+        let diff = (self.current.mean_nanos - self.baseline.mean_nanos).abs();
+
+        if standard_error == 0.0 {
+            return diff > 0.0;
+        }
+
+        diff / standard_error > CRITICAL_T
+    }
+}
+
+/// Compare the benched parts of a run against the baseline in the stored timings.
+/// Parts without sample statistics on either side can't be compared and are left out.
+pub fn compare(stored: &Timings, current: &Timings, baseline: Baseline) -> Vec<PartComparison> {
+    let mut comparisons = vec![];
+
+    for timing in &current.data {
+        for part in [1, 2] {
+            let Some(current) = get_stats(timing, part) else {
+                continue;
+            };
+
+            let baseline = match baseline {
+                Baseline::Previous => stored
+                    .data
+                    .iter()
+                    .find(|t| t.day == timing.day)
+                    .and_then(|t| get_stats(t, part)),
+                Baseline::Best => stored
+                    .history_of(timing.day)
+                    .filter_map(|t| get_stats(t, part))
+                    .min_by(|a, b| a.mean_nanos.total_cmp(&b.mean_nanos)),
+            };
+
+            if let Some(baseline) = baseline {
+                comparisons.push(PartComparison {
+                    day: timing.day,
+                    part,
+                    baseline: baseline.clone(),
+                    current: current.clone(),
+                });
+            }
+        }
+    }
+
+    comparisons
+}
+
+/// Whether any part regressed by more than `threshold` percent.
+pub fn exceeds_threshold(comparisons: &[PartComparison], threshold: f64) -> bool {
+    comparisons
+        .iter()
+        .any(|c| c.is_regression() && c.delta_percent() > threshold)
+}
+
+/// Print a line per compared part, followed by a summary.
+pub fn print_comparisons(comparisons: &[PartComparison], baseline: Baseline) {
+    println!();
+    println!("{ANSI_BOLD}Compared to {baseline} run:{ANSI_RESET}");
+
+    if comparisons.is_empty() {
+        println!("No stored timings with sample statistics to compare against.");
+        return;
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let to_duration = |nanos: f64| Duration::from_nanos(nanos as u64);
+
+    for comparison in comparisons {
+        let flag = if comparison.is_regression() {
+            " ✘ regression"
+        } else if comparison.is_improvement() {
+            " ✔ improvement"
+        } else {
+            ""
+        };
+
+        println!(
+            "Day {} Part {}: {:.1?} → {:.1?} ({:+.1}%){flag}",
+            comparison.day,
+            comparison.part,
+            to_duration(comparison.baseline.mean_nanos),
+            to_duration(comparison.current.mean_nanos),
+            comparison.delta_percent(),
+        );
+    }
+
+    let regressions = comparisons.iter().filter(|c| c.is_regression()).count();
+    let improvements = comparisons.iter().filter(|c| c.is_improvement()).count();
+    println!("{regressions} regression(s), {improvements} improvement(s)");
+}
+
+fn get_stats(timing: &Timing, part: u8) -> Option<&BenchStats> {
+    match part {
+        1 => timing.part_1_stats.as_ref(),
+        2 => timing.part_2_stats.as_ref(),
+        _ => None,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, exceeds_threshold, Baseline};
+    use crate::day;
+    use crate::template::timings::{BenchStats, RunInfo, Timing, Timings};
+
+    fn stats(mean_nanos: f64, std_dev_nanos: f64) -> BenchStats {
+        BenchStats {
+            samples: 100,
+            mean_nanos,
+            median_nanos: mean_nanos,
+            min_nanos: mean_nanos,
+            std_dev_nanos,
+        }
+    }
+
+    fn timing(part_1_stats: Option<BenchStats>, timestamp: u64) -> Timing {
+        Timing {
+            day: day!(6),
+            parse_nanos: None,
+            part_1_nanos: part_1_stats.as_ref().map(|s| s.mean_nanos),
+            part_2_nanos: None,
+            part_1_stats,
+            part_2_stats: None,
+            part_1_allocs: None,
+            part_2_allocs: None,
+            part_1_timed_out: false,
+            part_2_timed_out: false,
+            total_nanos: 0.0,
+            run: RunInfo {
+                timestamp,
+                ..RunInfo::default()
+            },
+        }
+    }
+
+    fn get_stored_timings() -> Timings {
+        Timings {
+            data: vec![timing(Some(stats(1200.0, 10.0)), 2)],
+            history: vec![timing(Some(stats(1000.0, 10.0)), 1)],
+        }
+    }
+
+    #[test]
+    fn parses_baselines() {
+        assert_eq!("previous".parse::<Baseline>(), Ok(Baseline::Previous));
+        assert_eq!("best".parse::<Baseline>(), Ok(Baseline::Best));
+        assert!("worst".parse::<Baseline>().is_err());
+    }
+
+    #[test]
+    fn compares_against_previous_run() {
+        let current = Timings {
+            data: vec![timing(Some(stats(1100.0, 10.0)), 3)],
+            history: vec![],
+        };
+
+        let comparisons = compare(&get_stored_timings(), &current, Baseline::Previous);
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].baseline.mean_nanos, 1200.0);
+        assert!(comparisons[0].is_improvement());
+        assert!(!comparisons[0].is_regression());
+    }
+
+    #[test]
+    fn compares_against_best_run() {
+        let current = Timings {
+            data: vec![timing(Some(stats(1100.0, 10.0)), 3)],
+            history: vec![],
+        };
+
+        let comparisons = compare(&get_stored_timings(), &current, Baseline::Best);
+        assert_eq!(comparisons[0].baseline.mean_nanos, 1000.0);
+        assert!((comparisons[0].delta_percent() - 10.0).abs() < 1e-9);
+        assert!(comparisons[0].is_regression());
+        assert!(exceeds_threshold(&comparisons, 5.0));
+        assert!(!exceeds_threshold(&comparisons, 15.0));
+    }
+
+    #[test]
+    fn ignores_noise() {
+        let current = Timings {
+            data: vec![timing(Some(stats(1210.0, 200.0)), 3)],
+            history: vec![],
+        };
+
+        let comparisons = compare(&get_stored_timings(), &current, Baseline::Previous);
+        assert!(!comparisons[0].is_regression());
+        assert!(!exceeds_threshold(&comparisons, 0.0));
+    }
+
+    #[test]
+    fn skips_parts_without_statistics() {
+        let stored = Timings {
+            data: vec![timing(None, 1)],
+            history: vec![],
+        };
+        let current = Timings {
+            data: vec![timing(Some(stats(1000.0, 10.0)), 2)],
+            history: vec![],
+        };
+
+        assert!(compare(&stored, &current, Baseline::Previous).is_empty());
+    }
+}
@@ -0,0 +1,396 @@
+/// Project settings, read from `aoc.toml` in the root of the repository.
+///
+/// The file is looked up in the working directory and its parents, falling back to the directory the template was
+/// compiled in. Relative paths in it resolve against the directory of the file, so commands and solution binaries
+/// work from any working directory.
+///
+/// ```toml
+/// year = 2024
+/// data_dir = "data"
+/// readme = "README.md"
+///
+/// [session]
+/// env = "AOC_SESSION"
+/// file = "~/.adventofcode.session"
+///
+/// [budgets]
+/// default = "10s"
+/// 6 = "1m"
+///
+/// [flags]
+/// all = ["--release"]
+/// ```
+use std::{
+    collections::HashMap,
+    env,
+    ffi::OsString,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use toml::{Table, Value};
+
+use crate::template::budgets::{BudgetSpec, Budgets};
+use crate::template::{Puzzle, Year};
+
+pub const CONFIG_FILE_NAME: &str = "aoc.toml";
+
+/// Directory of the solution binaries, relative to the root. Cargo only discovers binaries in this directory.
+const BIN_DIR: &str = "src/bin";
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Returns the project's config, loading it on first use.
+///
+/// # Panics
+/// If `aoc.toml` can't be read or is invalid. Use [`load_config`] to handle these errors.
+pub fn config() -> &'static Config {
+    CONFIG.get_or_init(|| Config::load().unwrap_or_else(|e| panic!("{e}")))
+}
+
+/// Returns the project's config, loading it on first use.
+pub fn load_config() -> Result<&'static Config, ConfigError> {
+    if let Some(config) = CONFIG.get() {
+        return Ok(config);
+    }
+
+    let config = Config::load()?;
+    Ok(CONFIG.get_or_init(|| config))
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    IO(PathBuf, io::Error),
+    Toml(PathBuf, toml::de::Error),
+    Invalid(String),
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::IO(path, e) => write!(f, "could not read \"{}\": {e}", path.display()),
+            ConfigError::Toml(path, e) => write!(f, "could not parse \"{}\": {e}", path.display()),
+            ConfigError::Invalid(message) => write!(f, "invalid {CONFIG_FILE_NAME}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Where the session cookie of the website is read from. The environment variable takes precedence.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SessionSource {
+    pub env: Option<String>,
+    pub file: Option<PathBuf>,
+}
+
+impl SessionSource {
+    /// Reads the raw session, if either source holds one.
+    pub fn read(&self) -> Option<String> {
+        let from_env = self.env.as_ref().and_then(|name| env::var(name).ok());
+        from_env.or_else(|| fs::read_to_string(self.file.as_ref()?).ok())
+    }
+}
+
+impl Display for SessionSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.env, &self.file) {
+            (Some(name), Some(file)) => {
+                write!(f, "set \"{name}\" or write it to \"{}\"", file.display())
+            }
+            (Some(name), None) => write!(f, "set \"{name}\""),
+            (None, Some(file)) => write!(f, "write it to \"{}\"", file.display()),
+            (None, None) => write!(f, "configure a session source in {CONFIG_FILE_NAME}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    /// The directory holding `aoc.toml`.
+    pub root: PathBuf,
+    /// The year of commands without a `--year`.
+    pub year: Option<Year>,
+    /// The directory holding a folder of inputs, examples and timings per year.
+    pub data_dir: PathBuf,
+    /// The readme that `cargo time --store` writes the benchmark table to.
+    pub readme: PathBuf,
+    pub session: SessionSource,
+    /// Time budgets of `cargo all`, `cargo time` and `cargo verify`. `--budget` arguments override them.
+    pub budgets: Budgets,
+    /// Flags added to the arguments of a command, by command name.
+    pub flags: HashMap<String, Vec<String>>,
+}
+
+impl Config {
+    /// Loads `aoc.toml` from the working directory or one of its parents, falling back to the directory the
+    /// template was compiled in. Without a file, the defaults apply relative to that directory.
+    pub fn load() -> Result<Self, ConfigError> {
+        let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+
+        let found = env::current_dir()
+            .ok()
+            .and_then(|cwd| {
+                cwd.ancestors()
+                    .find(|dir| dir.join(CONFIG_FILE_NAME).is_file())
+                    .map(Path::to_path_buf)
+            })
+            .unwrap_or(manifest_dir);
+
+        let path = found.join(CONFIG_FILE_NAME);
+        if !path.is_file() {
+            return Self::parse(&found, "");
+        }
+
+        let contents = fs::read_to_string(&path).map_err(|e| ConfigError::IO(path, e))?;
+        Self::parse(&found, &contents)
+    }
+
+    /// Parses the contents of an `aoc.toml` in `root`.
+    pub fn parse(root: &Path, contents: &str) -> Result<Self, ConfigError> {
+        let table = contents
+            .parse::<Table>()
+            .map_err(|e| ConfigError::Toml(root.join(CONFIG_FILE_NAME), e))?;
+        Self::from_table(root, &table)
+    }
+
+    fn from_table(root: &Path, table: &Table) -> Result<Self, ConfigError> {
+        let year = match table.get("year") {
+            None => None,
+            Some(value) => Some(
+                value
+                    .as_integer()
+                    .and_then(|year| u16::try_from(year).ok())
+                    .and_then(Year::new)
+                    .ok_or_else(|| invalid("Expected year to be a year of advent of code"))?,
+            ),
+        };
+
+        let data_dir = root.join(get_str(table, "data_dir", "data_dir")?.unwrap_or("data"));
+        let readme = root.join(get_str(table, "readme", "readme")?.unwrap_or("README.md"));
+
+        let session = match table.get("session") {
+            None => SessionSource {
+                env: Some("AOC_SESSION".into()),
+                file: home_dir().map(|home| home.join(".adventofcode.session")),
+            },
+            Some(Value::Table(session)) => SessionSource {
+                env: get_str(session, "env", "session.env")?.map(Into::into),
+                file: get_str(session, "file", "session.file")?
+                    .map(|file| resolve_file(root, file)),
+            },
+            Some(_) => return Err(invalid("Expected session to be a table")),
+        };
+
+        let budgets = match table.get("budgets") {
+            None => Budgets::default(),
+            Some(Value::Table(budgets)) => budgets
+                .iter()
+                .map(|(key, value)| parse_budget(key, value))
+                .collect::<Result<_, _>>()?,
+            Some(_) => return Err(invalid("Expected budgets to be a table")),
+        };
+
+        let flags = match table.get("flags") {
+            None => HashMap::new(),
+            Some(Value::Table(flags)) => flags
+                .iter()
+                .map(|(command, value)| {
+                    let args = value
+                        .as_array()
+                        .and_then(|args| {
+                            args.iter()
+                                .map(|arg| arg.as_str().map(Into::into))
+                                .collect::<Option<Vec<String>>>()
+                        })
+                        .ok_or_else(|| {
+                            invalid(format!(
+                                "Expected flags.{command} to be an array of strings"
+                            ))
+                        })?;
+                    Ok((command.clone(), args))
+                })
+                .collect::<Result<_, ConfigError>>()?,
+            Some(_) => return Err(invalid("Expected flags to be a table")),
+        };
+
+        Ok(Config {
+            root: root.into(),
+            year,
+            data_dir,
+            readme,
+            session,
+            budgets,
+            flags,
+        })
+    }
+
+    /// Returns the path of a puzzle's solution binary.
+    pub fn bin_path(&self, puzzle: Puzzle) -> PathBuf {
+        self.root.join(BIN_DIR).join(format!("{puzzle}.rs"))
+    }
+
+    /// Returns the link to a puzzle's solution binary from the readme, e.g. `./src/bin/2024-01.rs`.
+    pub fn bin_link(&self, puzzle: Puzzle) -> String {
+        let readme_dir = self.readme.parent().unwrap_or(Path::new(""));
+        let bin_path = self.bin_path(puzzle);
+
+        let mut up = String::from("./");
+        for dir in readme_dir.ancestors() {
+            if let Ok(relative) = bin_path.strip_prefix(dir) {
+                let relative: Vec<_> = relative
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect();
+                return format!("{up}{}", relative.join("/"));
+            }
+            up = if up == "./" { "../".into() } else { up + "../" };
+        }
+
+        bin_path.display().to_string()
+    }
+
+    /// Adds the configured flags of a command to its arguments, unless they were passed already.
+    /// The arguments start with the command, the flags are inserted right after it.
+    pub fn with_flags(&self, mut args: Vec<OsString>) -> Vec<OsString> {
+        let Some(flags) = args
+            .first()
+            .and_then(|command| self.flags.get(command.to_str()?))
+        else {
+            return args;
+        };
+
+        let missing: Vec<OsString> = flags
+            .iter()
+            .filter(|flag| !args.iter().any(|arg| arg == flag.as_str()))
+            .map(OsString::from)
+            .collect();
+
+        args.splice(1..1, missing);
+        args
+    }
+}
+
+impl Default for Config {
+    /// The defaults, with paths relative to the working directory.
+    fn default() -> Self {
+        Self::parse(Path::new(""), "").unwrap()
+    }
+}
+
+fn invalid(message: impl Display) -> ConfigError {
+    ConfigError::Invalid(message.to_string())
+}
+
+fn get_str<'a>(table: &'a Table, key: &str, name: &str) -> Result<Option<&'a str>, ConfigError> {
+    match table.get(key) {
+        None => Ok(None),
+        Some(value) => value
+            .as_str()
+            .map(Some)
+            .ok_or_else(|| invalid(format!("Expected {name} to be a string"))),
+    }
+}
+
+/// A budget is either `default` or a day, set to a duration like `"10s"` or a number of seconds.
+fn parse_budget(key: &str, value: &Value) -> Result<BudgetSpec, ConfigError> {
+    let duration = match value {
+        Value::String(s) => s.clone(),
+        Value::Integer(i) => i.to_string(),
+        Value::Float(f) => f.to_string(),
+        _ => return Err(invalid(format!("Expected budgets.{key} to be a duration"))),
+    };
+
+    let spec = if key == "default" {
+        duration
+    } else {
+        format!("{key}={duration}")
+    };
+
+    spec.parse().map_err(invalid)
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+}
+
+/// Expands a leading `~` to the home directory, other relative paths resolve against the root.
+fn resolve_file(root: &Path, file: &str) -> PathBuf {
+    match file.strip_prefix("~/") {
+        Some(rest) => home_dir().map_or_else(|| PathBuf::from(file), |home| home.join(rest)),
+        None => root.join(file),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{path::Path, time::Duration};
+
+    use super::Config;
+    use crate::{day, template::Puzzle, year};
+
+    #[test]
+    fn resolves_paths_against_the_root() {
+        let config = Config::parse(
+            Path::new("/aoc"),
+            "year = 2023\ndata_dir = \"puzzles\"\nreadme = \"docs/README.md\"\n[session]\nfile = \"session.txt\"",
+        )
+        .unwrap();
+
+        assert_eq!(config.year, Some(year!(2023)));
+        assert_eq!(config.data_dir, Path::new("/aoc/puzzles"));
+        assert_eq!(config.session.env, None);
+        assert_eq!(
+            config.session.file.as_deref(),
+            Some(Path::new("/aoc/session.txt"))
+        );
+
+        let puzzle = Puzzle::new(year!(2023), day!(1)).unwrap();
+        assert_eq!(
+            config.bin_path(puzzle),
+            Path::new("/aoc/src/bin/2023-01.rs")
+        );
+        assert_eq!(config.bin_link(puzzle), "../src/bin/2023-01.rs");
+        assert_eq!(Config::default().bin_link(puzzle), "./src/bin/2023-01.rs");
+    }
+
+    #[test]
+    fn reads_budgets_and_flags() {
+        let config = Config::parse(
+            Path::new(""),
+            "[budgets]\ndefault = \"10s\"\n6 = 60\n[flags]\nall = [\"--release\", \"--isolated\"]",
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.budgets.for_day(day!(1)),
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(
+            config.budgets.for_day(day!(6)),
+            Some(Duration::from_secs(60))
+        );
+
+        assert_eq!(
+            config.with_flags(vec!["all".into(), "--isolated".into()]),
+            vec!["all", "--release", "--isolated"]
+        );
+        assert_eq!(config.with_flags(vec!["time".into()]), vec!["time"]);
+    }
+
+    #[test]
+    fn rejects_invalid_values() {
+        let root = Path::new("");
+        assert!(Config::parse(root, "year = 1999").is_err());
+        assert!(Config::parse(root, "data_dir = 1").is_err());
+        assert!(Config::parse(root, "[budgets]\ndefault = \"10h\"").is_err());
+        assert!(Config::parse(root, "[flags]\nall = \"--release\"").is_err());
+        assert!(Config::parse(root, "year = ").is_err());
+    }
+}
@@ -0,0 +1,248 @@
+/// Minimal HTML to markdown conversion for puzzle descriptions.
+///
+/// Only covers the handful of tags used on adventofcode.com: headings, paragraphs, emphasis, code,
+/// preformatted blocks, lists and links. Unknown tags are dropped, their content is kept.
+pub fn html_to_markdown(html: &str) -> String {
+    let mut converter = Converter::default();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        converter.text(&rest[..start]);
+
+        let Some(end) = rest[start..].find('>') else {
+            rest = &rest[start..];
+            break;
+        };
+
+        converter.tag(&rest[start + 1..start + end]);
+        rest = &rest[start + end + 1..];
+    }
+
+    converter.text(rest);
+    converter.finish()
+}
+
+/// Returns the contents of every `<article>` element in a page.
+pub fn extract_articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else {
+            break;
+        };
+
+        let content_start = start + open_end + 1;
+
+        let Some(len) = rest[content_start..].find("</article>") else {
+            break;
+        };
+
+        articles.push(&rest[content_start..content_start + len]);
+        rest = &rest[content_start + len..];
+    }
+
+    articles
+}
+
+#[derive(Default)]
+struct Converter {
+    output: String,
+    links: Vec<String>,
+    code_depth: usize,
+    in_pre: bool,
+}
+
+impl Converter {
+    fn tag(&mut self, tag: &str) {
+        let (is_closing, tag) = match tag.strip_prefix('/') {
+            Some(tag) => (true, tag),
+            None => (false, tag),
+        };
+
+        let (name, attributes) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+
+        match (name.to_ascii_lowercase().as_str(), is_closing) {
+            ("h1" | "h2" | "h3", false) => {
+                self.block();
+                self.output.push_str("## ");
+            }
+            ("p" | "ul" | "ol", false) => self.block(),
+            ("h1" | "h2" | "h3" | "p" | "ul" | "ol", true) => self.block(),
+            ("li", false) => {
+                self.line();
+                self.output.push_str("- ");
+            }
+            ("li", true) => self.line(),
+            ("br", _) => self.output.push('\n'),
+            ("pre", false) => {
+                self.block();
+                self.output.push_str("```\n");
+                self.in_pre = true;
+            }
+            ("pre", true) => {
+                self.line();
+                self.output.push_str("```");
+                self.in_pre = false;
+                self.block();
+            }
+            ("code", false) => {
+                if !self.in_pre {
+                    self.output.push('`');
+                }
+                self.code_depth += 1;
+            }
+            ("code", true) => {
+                self.code_depth = self.code_depth.saturating_sub(1);
+                if !self.in_pre {
+                    self.output.push('`');
+                }
+            }
+            ("em" | "strong" | "b" | "i", _) if self.code_depth == 0 => self.output.push('*'),
+            ("a", false) => {
+                self.links
+                    .push(get_attribute(attributes, "href").unwrap_or_default());
+                self.output.push('[');
+            }
+            ("a", true) => {
+                let href = self.links.pop().unwrap_or_default();
+                self.output.push_str(&format!("]({href})"));
+            }
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        let text = decode_entities(text);
+
+        if self.in_pre {
+            self.output.push_str(&text);
+            return;
+        }
+
+        for (i, word) in text.split(char::is_whitespace).enumerate() {
+            let at_line_start = self.output.is_empty() || self.output.ends_with('\n');
+
+            if i > 0 && !at_line_start && !self.output.ends_with(' ') {
+                self.output.push(' ');
+            }
+
+            self.output.push_str(word);
+        }
+    }
+
+    /// Start a new line, unless already at the start of one.
+    fn line(&mut self) {
+        if !self.output.is_empty() && !self.output.ends_with('\n') {
+            self.output.push('\n');
+        }
+    }
+
+    /// Start a new block separated by an empty line, unless already at the start of one.
+    fn block(&mut self) {
+        self.line();
+        if !self.output.is_empty() && !self.output.ends_with("\n\n") {
+            self.output.push('\n');
+        }
+    }
+
+    fn finish(self) -> String {
+        let lines: Vec<&str> = self.output.lines().map(str::trim_end).collect();
+        format!("{}\n", lines.join("\n").trim())
+    }
+}
+
+fn get_attribute(attributes: &str, name: &str) -> Option<String> {
+    let start = attributes.find(&format!("{name}=\""))? + name.len() + 2;
+    let len = attributes[start..].find('"')?;
+    Some(decode_entities(&attributes[start..start + len]))
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';').and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => entity
+                    .strip_prefix("#x")
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                    .and_then(char::from_u32),
+            };
+            c.map(|c| (c, end))
+        });
+
+        match decoded {
+            Some((c, end)) => {
+                output.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                output.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    output.push_str(rest);
+    output
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{extract_articles, html_to_markdown};
+
+    #[test]
+    fn converts_puzzle_descriptions() {
+        let html = r#"<h2>--- Day 1: Historian Hysteria ---</h2><p>The <em>Chief Historian</em> is
+always present for the big Christmas sleigh launch, see <a href="/2024/day/1/input" target="_blank">your input</a>.</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<ul><li>Pair up <code>1</code> and <code><em>3</em></code>.</li><li>Then sum &lt;all&gt; distances.</li></ul>"#;
+
+        assert_eq!(
+            html_to_markdown(html),
+            "## --- Day 1: Historian Hysteria ---
+
+The *Chief Historian* is always present for the big Christmas sleigh launch, see [your input](/2024/day/1/input).
+
+```
+3   4
+4   3
+```
+
+- Pair up `1` and `3`.
+- Then sum <all> distances.
+"
+        );
+    }
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(
+            html_to_markdown("a &amp;&amp; b &#39;c&#x27; &unknown; &"),
+            "a && b 'c' &unknown; &\n"
+        );
+    }
+
+    #[test]
+    fn extracts_articles() {
+        let html = r#"<main><article class="day-desc"><p>one</p></article><p>x</p><article class="day-desc"><p>two</p></article></main>"#;
+        assert_eq!(extract_articles(html), vec!["<p>one</p>", "<p>two</p>"]);
+    }
+}
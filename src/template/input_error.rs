@@ -0,0 +1,185 @@
+/// Errors in puzzle inputs that point at the line and column where they were found.
+///
+/// Parts return `Result<T, InputError>` (or any other error that wraps one as its source) to report a broken
+/// input instead of panicking. The runner prints the message together with the offending line:
+///
+/// ```text
+/// Part 1: ✘ line 37, column 4: invalid digit found in string
+///    37 | 12 x4
+///       |    ^
+/// ```
+use std::{error::Error, fmt::Display};
+
+/// Where in the input an error was found.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputLocation {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters. [`None`] if the whole line is at fault.
+    pub column: Option<usize>,
+    /// The text of the line.
+    pub text: String,
+}
+
+impl InputLocation {
+    /// The line with a gutter holding its number and, if there is a column, a caret pointing at it.
+    pub fn context(&self) -> String {
+        let gutter = self.line.to_string();
+        let mut context = format!("{gutter:>4} | {}", self.text);
+
+        if let Some(column) = self.column {
+            let padding = " ".repeat(gutter.len().max(4));
+            let offset = " ".repeat(column - 1);
+            context.push_str(&format!("\n{padding} | {offset}^"));
+        }
+
+        context
+    }
+}
+
+impl Display for InputLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.column {
+            Some(column) => write!(f, "line {}, column {column}", self.line),
+            None => write!(f, "line {}", self.line),
+        }
+    }
+}
+
+/// An error in a puzzle input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputError {
+    pub message: String,
+    pub location: InputLocation,
+}
+
+impl InputError {
+    /// An error on a line of the input. `index` is 0-based, as yielded by `input.lines().enumerate()`.
+    pub fn on_line(index: usize, text: &str, message: impl Display) -> Self {
+        InputError {
+            message: message.to_string(),
+            location: InputLocation {
+                line: index + 1,
+                column: None,
+                text: text.into(),
+            },
+        }
+    }
+
+    /// An error at a token of the input. `token` has to be a slice of `input`, e.g. a word of a split line.
+    ///
+    /// ```
+    /// # use advent_of_code::template::input_error::InputError;
+    /// let input = "3   4\n4   x\n";
+    /// let token = input.split_whitespace().nth(3).unwrap();
+    /// assert_eq!(InputError::at(input, token, "not a number").to_string(), "line 2, column 5: not a number");
+    /// ```
+    pub fn at(input: &str, token: &str, message: impl Display) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| *offset <= input.len())
+            .expect("token has to be a slice of the input");
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        InputError {
+            message: message.to_string(),
+            location: InputLocation {
+                line: input[..line_start].matches('\n').count() + 1,
+                column: Some(input[line_start..offset].chars().count() + 1),
+                text: input[line_start..line_end].trim_end_matches('\r').into(),
+            },
+        }
+    }
+
+    /// Point the error at a column of its line. `column` is a 0-based character index.
+    #[must_use]
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.location.column = Some(column + 1);
+        self
+    }
+
+    /// Find an [`InputError`] in an error or its chain of sources.
+    pub fn find<'a>(error: &'a (dyn Error + 'static)) -> Option<&'a InputError> {
+        let mut source = Some(error);
+
+        while let Some(error) = source {
+            if let Some(input_error) = error.downcast_ref::<InputError>() {
+                return Some(input_error);
+            }
+            source = error.source();
+        }
+
+        None
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.location, self.message)
+    }
+}
+
+impl Error for InputError {}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{error::Error, fmt::Display};
+
+    use super::{InputError, InputLocation};
+
+    #[test]
+    fn locates_tokens() {
+        let input = "3   4\n4   3\n12 x4\n";
+        let token = input.lines().nth(2).unwrap().split(' ').nth(1).unwrap();
+
+        let error = InputError::at(input, token, "invalid digit");
+        assert_eq!(
+            error.location,
+            InputLocation {
+                line: 3,
+                column: Some(4),
+                text: "12 x4".into(),
+            }
+        );
+        assert_eq!(error.to_string(), "line 3, column 4: invalid digit");
+        assert_eq!(error.location.context(), "   3 | 12 x4\n     |    ^");
+    }
+
+    #[test]
+    fn locates_lines() {
+        let error = InputError::on_line(36, "1 2 3", "expected two numbers");
+        assert_eq!(error.to_string(), "line 37: expected two numbers");
+        assert_eq!(error.location.context(), "  37 | 1 2 3");
+
+        let error = error.at_column(2);
+        assert_eq!(error.to_string(), "line 37, column 3: expected two numbers");
+    }
+
+    #[derive(Debug)]
+    struct Wrapper(InputError);
+
+    impl Display for Wrapper {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "could not parse the input")
+        }
+    }
+
+    impl Error for Wrapper {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[test]
+    fn finds_sources() {
+        let error = Wrapper(InputError::on_line(0, "", "empty line"));
+        assert_eq!(InputError::find(&error), Some(&error.0));
+        assert_eq!(InputError::find(&std::fmt::Error), None);
+    }
+}
@@ -1,68 +1,369 @@
-use std::{env, fs};
+use std::{fs, io, path::PathBuf};
 
-pub mod aoc_cli;
+use config::config;
+
+pub mod allocations;
+pub mod aoc_client;
+pub mod budgets;
 pub mod commands;
+pub mod compare;
+pub mod config;
+pub mod input_error;
+pub mod params;
+pub mod registry;
 pub mod runner;
+pub mod solution_args;
 
 pub use day::*;
+pub use puzzle::*;
+pub use year::*;
 
+mod answers;
 mod day;
+mod html_to_markdown;
+mod named_inputs;
+mod puzzle;
 mod readme_benchmarks;
 mod run_multi;
+mod submissions;
 mod timings;
+mod variants;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Returns the path of a year's data directory, e.g. `data/2024`, in the data root of `aoc.toml`.
+pub fn data_dir(year: Year) -> PathBuf {
+    config().data_dir.join(year.to_string())
+}
+
+/// Returns the path of a puzzle's file in a data folder, e.g. `data/2024/inputs/01.txt`.
+pub fn data_file_path(folder: &str, puzzle: Puzzle) -> PathBuf {
+    data_dir(puzzle.year)
+        .join(folder)
+        .join(format!("{}.txt", puzzle.day))
+}
+
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file(folder: &str, puzzle: Puzzle) -> String {
+    try_read_file(folder, puzzle).expect("could not open input file")
+}
+
+/// Helper function that reads a text file to a string, returning an error if it can't be read.
+pub fn try_read_file(folder: &str, puzzle: Puzzle) -> io::Result<String> {
+    fs::read_to_string(data_file_path(folder, puzzle))
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
+pub fn read_file_part(folder: &str, puzzle: Puzzle, part: u8) -> String {
+    try_read_file_part(folder, puzzle, part).expect("could not open input file")
+}
+
+/// Helper function that reads a text file with a part suffix to a string, returning an error if it can't be read.
+pub fn try_read_file_part(folder: &str, puzzle: Puzzle, part: u8) -> io::Result<String> {
+    let filepath = data_dir(puzzle.year)
         .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+        .join(format!("{}-{part}.txt", puzzle.day));
+    fs::read_to_string(filepath)
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constant `PUZZLE` and sets up the input and runner for each part.
+/// Also creates the constant `SOLUTION`, which registers the solution so that `cargo all` and `cargo time`
+/// can run it in-process.
+///
+/// The optional, third parameter (1 or 2) allows you to only run a single part of the solution.
+/// It can be followed by options, in this order:
+///
+/// - `parse = <fn>`: parses the input once, before the parts run. The parts receive a reference to the parsed
+///   input instead of the raw input. The parse time is reported separately.
+/// - `try_parse = <fn>`: same as `parse`, for parses that return a `Result`. If the parse fails, the parts don't
+///   run.
+/// - `solve = <fn>`: computes the answers of both parts at once and returns them as a tuple. It replaces
+///   `part_one` and `part_two`.
+/// - `variants = [<part> => <fn>, ...]`: registers other implementations of a part, e.g. `[2 => part_two_bitset]`.
+///   `cargo time <day> --variants` benches them against the part, and the example tests check them as well.
+/// - `params = <type>`: puzzles with parameters, e.g. a grid size that differs between the example and the
+///   real input, declare them with [`params!`]. The parts receive them as a second argument.
+///
+/// ```ignore
+/// advent_of_code::solution!(2024, 5, parse = parse, solve = solve);
+///
+/// fn parse(input: &str) -> Vec<u32> { /* ... */ }
+///
+/// pub fn solve(input: &Vec<u32>) -> (Option<u32>, Option<u32>) { /* ... */ }
+/// ```
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// Parts return an `Option` of their answer, or a `Result` if they can fail. See [`runner::Answer`].
+///
+/// In tests, the macro also generates a test per example file and part with an answer declared
+/// next to it, e.g. in `data/2024/examples/01.answers`. See `build.rs` for the format.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($year:expr, $day:expr, 1 $(, $($options:tt)*)?) => {
+        $crate::solution!(@options [$year, $day, [[part_one, 1]]] [] [] [] $($($options)*)?);
+    };
+    ($year:expr, $day:expr, 2 $(, $($options:tt)*)?) => {
+        $crate::solution!(@options [$year, $day, [[part_two, 2]]] [] [] [] $($($options)*)?);
+    };
+    ($year:expr, $day:expr $(, $($options:tt)*)?) => {
+        $crate::solution!(@options [$year, $day, [[part_one, 1] [part_two, 2]]] [] [] [] $($($options)*)?);
+    };
+
+    // collect the options into `[parse] [solve] [variants]`, followed by the params.
+    (@options $head:tt [] [] [] parse = $parse:path $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $head [$crate::template::runner::infallible($parse)] [] [] $($($rest)*)?);
+    };
+    (@options $head:tt [] [] [] try_parse = $parse:path $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $head [$parse] [] [] $($($rest)*)?);
+    };
+    (@options $head:tt $parse:tt [] [] solve = $solve:path $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $head $parse [$solve] [] $($($rest)*)?);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    (@options $head:tt $parse:tt $solve:tt [] variants = [$($vpart:literal => $variant:ident),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $head $parse $solve [$( [$variant, $vpart] )*] $($($rest)*)?);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    (@options $head:tt $parse:tt $solve:tt $variants:tt params = $params:ty $(,)?) => {
+        $crate::solution!(@params $head $parse $solve $variants [$params]);
+    };
+    (@options $head:tt $parse:tt $solve:tt $variants:tt) => {
+        $crate::solution!(@params $head $parse $solve $variants []);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
-        /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+    // parts of puzzles without parameters ignore them.
+    (@params
+        [$year:expr, $day:expr, [$( [$func:ident, $part:expr] )*]] [$($parse:expr)?] [$($solve:path)?]
+        [$( [$variant:ident, $vpart:expr] )*] []
+    ) => {
+        $crate::solution!(
+            @impl $year, $day, (), [$($parse)?],
+            [$($crate::template::params::ignore_params($solve))?],
+            [$( [$crate::template::params::ignore_params($variant), stringify!($variant), $vpart] )*],
+            $( [$crate::template::params::ignore_params($func), $part] )*
+        );
+    };
+    (@params
+        [$year:expr, $day:expr, [$( [$func:ident, $part:expr] )*]] [$($parse:expr)?] [$($solve:path)?]
+        [$( [$variant:ident, $vpart:expr] )*] [$params:ty]
+    ) => {
+        $crate::solution!(
+            @impl $year, $day, $params, [$($parse)?], [$($solve)?],
+            [$( [$variant, stringify!($variant), $vpart] )*],
+            $( [$func, $part] )*
+        );
+    };
+
+    // the name of the solution's own implementation of a part.
+    (@own_name [$solve:expr], $part:expr) => {
+        "solve"
+    };
+    (@own_name [], $part:expr) => {
+        if $part == 1 { "part_one" } else { "part_two" }
+    };
+
+    // run either the combined solve or the parts, only the selected one if a part was picked.
+    (@run $input:ident, $params:ident, $only:ident, $options:ident, [$solve:expr], $( [$func:expr, $part:expr] )*) => {
+        $crate::template::runner::run_solve_with($solve, $input, $params, PUZZLE, $only, $options)
+    };
+    (@run $input:ident, $params:ident, $only:ident, $options:ident, [], $( [$func:expr, $part:expr] )*) => {{
+        let mut reports = vec![];
+        $(
+            if $only.is_none_or(|only| only == $part) {
+                reports.push($crate::template::runner::run_part_with($func, $input, $params, PUZZLE, $part, $options));
+            }
+        )*
+        reports
+    }};
+
+    // run the variant with the given name.
+    (@run_variant $input:ident, $params:ident, $name:ident, $options:ident, $( [$vfunc:expr, $vname:expr, $vpart:expr] )*) => {{
+        #[allow(unused_mut)]
+        let mut reports = vec![];
+        $(
+            if $name == $vname {
+                reports.push($crate::template::runner::run_part_with($vfunc, $input, $params, PUZZLE, $vpart, $options));
+            }
+        )*
+        let _ = ($input, $params, $name, $options);
+        reports
+    }};
+
+    // compute the answer of a part without reporting it.
+    (@answer $input:ident, $params:ident, $part_number:ident, [$solve:expr], $( [$func:expr, $part:expr] )*) => {{
+        use $crate::template::runner::Answer;
+
+        let (part_1, part_2) = $solve($input, $params);
+        let answer = match $part_number {
+            1 => part_1.to_answer(),
+            2 => part_2.to_answer(),
+            _ => panic!("part {} is not part of the solution", $part_number),
+        };
+        answer.unwrap_or_else(|e| panic!("{e}"))
+    }};
+    (@answer $input:ident, $params:ident, $part_number:ident, [], $( [$func:expr, $part:expr] )*) => {{
+        use $crate::template::runner::Answer;
+
+        $(
+            if $part_number == $part {
+                return $func($input, $params).to_answer().unwrap_or_else(|e| panic!("{e}"));
+            }
+        )*
+
+        panic!("part {} is not part of the solution", $part_number)
+    }};
+    (@answer_variant $input:ident, $params:ident, $name:ident, $( [$vfunc:expr, $vname:expr, $vpart:expr] )*) => {{
+        #[allow(unused_imports)]
+        use $crate::template::runner::Answer;
+
+        $(
+            if $name == $vname {
+                return $vfunc($input, $params).to_answer().unwrap_or_else(|e| panic!("{e}"));
+            }
+        )*
+
+        let _ = ($input, $params);
+        panic!("`{}` is not a variant of the solution", $name)
+    }};
+
+    (@impl
+        $year:expr, $day:expr, $params:ty, [$($parse:expr)?], $solve:tt,
+        [$( [$vfunc:expr, $vname:expr, $vpart:expr] )*],
+        $( [$func:expr, $part:expr] )*
+    ) => {
+        /// The current puzzle.
+        const PUZZLE: $crate::template::Puzzle = $crate::puzzle!($year, $day);
+
+        /// The solution of the current puzzle, picked up by the solution registry.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::Solution =
+            $crate::template::registry::Solution {
+                puzzle: PUZZLE,
+                run: |input, options| __run(input, &<$params as Default>::default(), None, None, options),
+                variants: &[
+                    $( $crate::template::registry::Variant {
+                        name: $crate::solution!(@own_name $solve, $part),
+                        part: $part,
+                    }, )*
+                    $( $crate::template::registry::Variant { name: $vname, part: $vpart }, )*
+                ],
+                run_variant: |input, variant, options| {
+                    let is_own = variant.name == $crate::solution!(@own_name $solve, variant.part);
+                    let name = (!is_own).then_some(variant.name);
+                    __run(input, &<$params as Default>::default(), Some(variant.part), name, options)
+                },
+            };
+
+        /// Run the parts of the solution against an input, parsing it first if the solution shares its parsed input.
+        /// With a `part`, only that part runs. With a `variant`, only that variant runs instead of the parts.
+        fn __run(
+            input: &str,
+            params: &$params,
+            part: Option<u8>,
+            variant: Option<&str>,
+            options: &$crate::template::runner::RunOptions,
+        ) -> $crate::template::runner::SolutionReport {
+            #[allow(unused_mut)]
+            let mut report = $crate::template::runner::SolutionReport::default();
+
+            $(
+                let (parsed, parse_report) = $crate::template::runner::run_parse_with($parse, input, PUZZLE, options);
+                report.parse = Some(parse_report);
+                let Some(parsed) = parsed else {
+                    return report;
+                };
+                let input = &parsed;
+            )?
+
+            report.parts = match variant {
+                None => $crate::solution!(@run input, params, part, options, $solve, $( [$func, $part] )*),
+                Some(name) => $crate::solution!(@run_variant input, params, name, options, $( [$vfunc, $vname, $vpart] )*),
+            };
+            report
+        }
 
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
         fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let args = $crate::template::solution_args::SolutionArgs::from_args();
+            let mut options = $crate::template::runner::RunOptions::from_args();
+            let is_puzzle_input = args.input == $crate::template::solution_args::InputSource::Puzzle;
+
+            if options.submit.is_some() && !is_puzzle_input {
+                eprintln!("Refusing to submit an answer that was not computed from the puzzle input.");
+                std::process::exit(1);
+            }
+
+            // NOTE: JSON output isn't checked, the process that reads it does that.
+            if is_puzzle_input && options.format == $crate::template::runner::OutputFormat::Human {
+                options.check_answers(PUZZLE.year);
+            }
+
+            if let Some(part) = args.part {
+                if ![$($part),*].contains(&part) {
+                    eprintln!("Part {part} is not part of the solution.");
+                    std::process::exit(1);
+                }
+            }
+
+            let input = args.read_input(PUZZLE);
+            let params: $params = args.read_params(PUZZLE);
+            let report = __run(&input, &params, args.part, None, &options);
+
+            if report.is_failed() {
+                std::process::exit(1);
+            }
+        }
+
+        /// Run a variant of a part against an example and return its answer, used by the generated example tests.
+        #[cfg(test)]
+        #[allow(dead_code)]
+        fn __example_answer(example: Option<u8>, part: u8, variant: &str) -> Option<String> {
+            let input = match example {
+                Some(n) => $crate::template::read_file_part("examples", PUZZLE, n),
+                None => $crate::template::read_file("examples", PUZZLE),
+            };
+            let input = input.as_str();
+
+            let params: $params = $crate::template::params::read_example_params(PUZZLE, example)
+                .unwrap_or_else(|e| panic!("{e}"));
+            let params = &params;
+
+            $(
+                let parsed = ($parse)(input).unwrap_or_else(|e| panic!("{e}"));
+                let input = &parsed;
+            )?
+
+            if variant == $crate::solution!(@own_name $solve, part) {
+                $crate::solution!(@answer input, params, part, $solve, $( [$func, $part] )*)
+            } else {
+                $crate::solution!(@answer_variant input, params, variant, $( [$vfunc, $vname, $vpart] )*)
+            }
+        }
+
+        #[cfg(test)]
+        mod examples {
+            /// Run each variant of a part against an example and compare its answer to the declared one.
+            #[allow(dead_code)]
+            fn check(example: Option<u8>, part: u8, expected: Option<&str>) {
+                let variants: Vec<_> = super::SOLUTION.variants_of(part).collect();
+                assert!(!variants.is_empty(), "part {part} is not part of the solution");
+
+                for variant in variants {
+                    assert_eq!(
+                        super::__example_answer(example, part, variant.name).as_deref(),
+                        expected,
+                        "variant `{}`",
+                        variant.name
+                    );
+                }
+            }
+
+            include!(concat!(env!("OUT_DIR"), "/examples/", $year, "-", $day, ".rs"));
         }
     };
 }
@@ -0,0 +1,319 @@
+/// Named inputs of a day, e.g. the puzzle inputs of everyone on a team, so that a solution can be checked against
+/// more than one input.
+///
+/// They live in a folder per day next to the puzzle inputs, e.g. `data/2024/inputs/13/alice.txt`. Their expected
+/// answers are declared in a sidecar file like those of the examples, e.g. `alice.answers`:
+///
+/// ```text
+/// part_1: 480
+/// part_2: 875318608908
+/// ```
+///
+/// A part without a line has no known answer, a part with an empty answer expects none.
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::template::answers::{AnswerCheck, Answers};
+use crate::template::run_multi::child_commands;
+use crate::template::runner::{PartReport, SolutionReport};
+use crate::template::solution_args::InputSource;
+use crate::template::{data_dir, Puzzle, ANSI_BOLD, ANSI_RESET};
+
+/// The expected answers of an input by part. [`None`] if a part expects no answer.
+pub type ExpectedAnswers = HashMap<u8, Option<String>>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamedInput {
+    pub name: String,
+    pub path: PathBuf,
+    pub expected: ExpectedAnswers,
+}
+
+/// Returns the folder of a day's named inputs, e.g. `data/2024/inputs/13`.
+pub fn named_inputs_dir(puzzle: Puzzle) -> PathBuf {
+    data_dir(puzzle.year)
+        .join("inputs")
+        .join(puzzle.day.to_string())
+}
+
+/// Read the named inputs of a day, ordered by name. Days without a folder of inputs have none.
+pub fn read_named_inputs(puzzle: Puzzle) -> Result<Vec<NamedInput>, String> {
+    let dir = named_inputs_dir(puzzle);
+
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(format!("{}: {e}", dir.display())),
+    };
+
+    let mut inputs = vec![];
+
+    for entry in entries {
+        let path = entry.map_err(|e| e.to_string())?.path();
+
+        if path.extension().is_none_or(|ext| ext != "txt") {
+            continue;
+        }
+
+        let Some(name) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+
+        inputs.push(NamedInput {
+            name: name.into(),
+            expected: read_expected_answers(&path.with_extension("answers"))?,
+            path,
+        });
+    }
+
+    inputs.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+    Ok(inputs)
+}
+
+/// Read the answers of an input from its sidecar file. Without one, no answers are known.
+fn read_expected_answers(path: &Path) -> Result<ExpectedAnswers, String> {
+    match fs::read_to_string(path) {
+        Ok(s) => parse_expected_answers(&s).map_err(|e| format!("{}: {e}", path.display())),
+        Err(_) => Ok(ExpectedAnswers::new()),
+    }
+}
+
+/// Parse answers like `part_1: 11`, one part per line.
+pub fn parse_expected_answers(s: &str) -> Result<ExpectedAnswers, String> {
+    let mut answers = ExpectedAnswers::new();
+
+    for line in s.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let part = match line.split_once(':') {
+            Some(("part_1", answer)) => (1, answer),
+            Some(("part_2", answer)) => (2, answer),
+            _ => {
+                return Err(format!(
+                    "unexpected line `{line}`, expecting `part_<1|2>: <answer>`"
+                ))
+            }
+        };
+
+        let answer = part.1.trim();
+        answers.insert(part.0, (!answer.is_empty()).then(|| answer.into()));
+    }
+
+    Ok(answers)
+}
+
+/// The report of a solution run against an input, along with the answers expected for it.
+#[derive(Clone, Debug)]
+pub struct InputRun {
+    pub name: String,
+    pub report: SolutionReport,
+    pub expected: ExpectedAnswers,
+}
+
+impl InputRun {
+    /// The run of the puzzle input, checked against the answer store.
+    pub fn puzzle(report: SolutionReport, answers: &Answers) -> Self {
+        let expected = report
+            .parts
+            .iter()
+            .filter_map(|part| {
+                let answer = answers.get(part.puzzle.day, part.part)?;
+                Some((part.part, Some(answer.to_string())))
+            })
+            .collect();
+
+        InputRun {
+            name: "puzzle".into(),
+            report,
+            expected,
+        }
+    }
+
+    /// Check the answer of a part against the expected one.
+    pub fn check(&self, part: &PartReport) -> AnswerCheck {
+        match self.expected.get(&part.part) {
+            None => AnswerCheck::Unknown,
+            Some(expected) if *expected == part.answer => AnswerCheck::Correct,
+            Some(expected) => AnswerCheck::Incorrect {
+                expected: expected.clone().unwrap_or_else(|| "nothing".into()),
+            },
+        }
+    }
+
+    /// The time the solution took, the sum of its parse and parts.
+    pub fn duration(&self) -> Duration {
+        let parse = self.report.parse.iter().map(|parse| parse.duration);
+        let parts = self.report.parts.iter().map(|part| part.duration);
+        parse.chain(parts).sum()
+    }
+
+    /// Whether the solution failed or gave a wrong answer for this input.
+    pub fn is_failed(&self) -> bool {
+        self.report.is_failed()
+            || self
+                .report
+                .parts
+                .iter()
+                .any(|part| matches!(self.check(part), AnswerCheck::Incorrect { .. }))
+    }
+}
+
+/// Run the solution of a day against each of its named inputs, built in release mode if `is_release` is set.
+/// Each input gets `budget` per stage.
+///
+/// Every input runs in a child process of its own. In-process, state that a solution keeps between runs, e.g. a
+/// global cache keyed on only part of the input, would carry the answers of one input over to the next.
+pub fn run_named_inputs(
+    puzzle: Puzzle,
+    inputs: Vec<NamedInput>,
+    is_release: bool,
+    budget: Option<Duration>,
+) -> Vec<InputRun> {
+    inputs
+        .into_iter()
+        .map(|input| {
+            print_heading(puzzle, &input.name);
+
+            let source = InputSource::File(input.path.clone());
+            let report = child_commands::run_input(puzzle, &source, is_release, budget, None)
+                .unwrap_or_else(|e| SolutionReport {
+                    failure: Some(format!("could not run the solution: {e:?}").into()),
+                    ..SolutionReport::default()
+                });
+
+            InputRun {
+                name: input.name,
+                report,
+                expected: input.expected,
+            }
+        })
+        .collect()
+}
+
+/// Print the heading of a run of an input, followed by the output of the solution.
+pub fn print_heading(puzzle: Puzzle, name: &str) {
+    println!();
+    println!("{ANSI_BOLD}Day {} · {name}{ANSI_RESET}", puzzle.day);
+    println!("------");
+}
+
+/// Print a table of the answers, their correctness and the time taken per input.
+pub fn print_table(puzzle: Puzzle, runs: &[InputRun]) {
+    println!();
+    println!("{ANSI_BOLD}Day {} inputs:{ANSI_RESET}", puzzle.day);
+    println!("| Input | Part 1 | Part 2 | Time |");
+    println!("| :--- | :--- | :--- | ---: |");
+
+    for run in runs {
+        let time = if run.report.is_failed() || run.report.is_timed_out() {
+            "-".into()
+        } else {
+            format!("{:.1?}", run.duration())
+        };
+
+        println!(
+            "| {} | {} | {} | {time} |",
+            run.name,
+            format_cell(run, 1),
+            format_cell(run, 2)
+        );
+    }
+}
+
+/// Format the answer of a part and whether it is correct, e.g. `✔ 11` or `✘ 12 (expected 11)`.
+fn format_cell(run: &InputRun, part: u8) -> String {
+    let Some(report) = run.report.parts.iter().find(|report| report.part == part) else {
+        // a failed or timed out parse keeps the parts from running.
+        return if run.report.is_failed() {
+            "✘ failed".into()
+        } else if run.report.is_timed_out() {
+            "⏱ timed out".into()
+        } else {
+            "-".into()
+        };
+    };
+
+    if report.timed_out {
+        return "⏱ timed out".into();
+    }
+
+    if report.failure.is_some() {
+        return "✘ failed".into();
+    }
+
+    let answer = report.answer.as_deref().unwrap_or("nothing");
+
+    match run.check(report) {
+        AnswerCheck::Correct => format!("✔ {answer}"),
+        AnswerCheck::Incorrect { expected } => format!("✘ {answer} (expected {expected})"),
+        AnswerCheck::Unknown => answer.into(),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_cell, parse_expected_answers, InputRun};
+    use crate::puzzle;
+    use crate::template::answers::AnswerCheck;
+    use crate::template::runner::{PartReport, RunOptions, SolutionReport};
+
+    fn run(answers: [Option<&str>; 2], expected: &str) -> InputRun {
+        let puzzle = puzzle!(2024, 13);
+
+        InputRun {
+            name: "alice".into(),
+            report: SolutionReport {
+                parts: (1..=2)
+                    .map(|part| PartReport {
+                        answer: answers[usize::from(part - 1)].map(Into::into),
+                        ..PartReport::unfinished(puzzle, part)
+                    })
+                    .collect(),
+                ..SolutionReport::default()
+            },
+            expected: parse_expected_answers(expected).unwrap(),
+        }
+    }
+
+    #[test]
+    fn parses_expected_answers() {
+        let answers = parse_expected_answers("part_1: 480\npart_2:\n").unwrap();
+        assert_eq!(answers.get(&1), Some(&Some("480".into())));
+        assert_eq!(answers.get(&2), Some(&None));
+
+        assert!(parse_expected_answers("part_1: 480")
+            .unwrap()
+            .get(&2)
+            .is_none());
+        assert!(parse_expected_answers("part_3: 1").is_err());
+    }
+
+    #[test]
+    fn checks_answers() {
+        let run = run([Some("480"), Some("12")], "part_1: 480\npart_2: 11");
+        let parts = &run.report.parts;
+
+        assert_eq!(run.check(&parts[0]), AnswerCheck::Correct);
+        assert_eq!(
+            run.check(&parts[1]),
+            AnswerCheck::Incorrect {
+                expected: "11".into()
+            }
+        );
+        assert!(run.is_failed());
+
+        assert_eq!(format_cell(&run, 1), "✔ 480");
+        assert_eq!(format_cell(&run, 2), "✘ 12 (expected 11)");
+
+        let run = InputRun {
+            expected: parse_expected_answers("part_2:").unwrap(),
+            ..run
+        };
+        assert_eq!(format_cell(&run, 1), "480");
+        assert_eq!(format_cell(&run, 2), "✘ 12 (expected nothing)");
+    }
+}
@@ -0,0 +1,155 @@
+/// Parameters of a puzzle that differ between the example and the real input, e.g. the size of a grid.
+///
+/// Solutions declare their parameters with the [`params!`](crate::params) macro and receive them as a second
+/// argument of their parts. The defaults apply to the real puzzle, examples override them in a sidecar file
+/// next to the example, e.g. `data/2024/examples/14.params` for `14.txt`:
+///
+/// ```text
+/// width: 11
+/// height: 7
+/// ```
+use std::fs;
+
+use crate::template::{data_dir, Puzzle};
+
+/// A typed set of puzzle parameters. Implemented by the [`params!`](crate::params) macro.
+pub trait PuzzleParams: Default {
+    /// Override a parameter by its name, parsing the value.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String>;
+}
+
+/// Puzzles without parameters.
+impl PuzzleParams for () {
+    fn set(&mut self, name: &str, _: &str) -> Result<(), String> {
+        Err(format!(
+            "unknown parameter `{name}`, the puzzle has no parameters"
+        ))
+    }
+}
+
+/// Adapt a part without parameters to the signature of parts with parameters.
+pub fn ignore_params<I, T>(func: impl Fn(I) -> T) -> impl Fn(I, &()) -> T {
+    move |input, _| func(input)
+}
+
+/// Apply the overrides of a parameter file, one `<name>: <value>` per line, to the default parameters.
+pub fn parse_params<P: PuzzleParams>(s: &str) -> Result<P, String> {
+    let mut params = P::default();
+
+    for line in s.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let Some((name, value)) = line.split_once(':') else {
+            return Err(format!(
+                "unexpected line `{line}`, expecting `<name>: <value>`"
+            ));
+        };
+
+        params.set(name.trim(), value.trim())?;
+    }
+
+    Ok(params)
+}
+
+/// Read the parameters of an example, e.g. from `data/2024/examples/14.params` or `14-2.params` for the second example.
+/// Without a parameter file, the defaults of the real puzzle apply.
+pub fn read_example_params<P: PuzzleParams>(
+    puzzle: Puzzle,
+    example: Option<u8>,
+) -> Result<P, String> {
+    let file_name = match example {
+        Some(n) => format!("{}-{n}.params", puzzle.day),
+        None => format!("{}.params", puzzle.day),
+    };
+
+    let path = data_dir(puzzle.year).join("examples").join(file_name);
+
+    match fs::read_to_string(&path) {
+        Ok(s) => parse_params(&s).map_err(|e| format!("{}: {e}", path.display())),
+        Err(_) => Ok(P::default()),
+    }
+}
+
+/// Declares the parameters of a puzzle as a struct with a default value per field, which applies to the real puzzle.
+///
+/// ```
+/// advent_of_code::params! {
+///     pub struct Params {
+///         width: isize = 101,
+///         height: isize = 103,
+///     }
+/// }
+///
+/// let params: Params = advent_of_code::template::params::parse_params("width: 11").unwrap();
+/// assert_eq!((params.width, params.height), (11, 103));
+/// ```
+#[macro_export]
+macro_rules! params {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $( $(#[$field_meta:meta])* $field:ident : $ty:ty = $default:expr ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug)]
+        $vis struct $name {
+            $( $(#[$field_meta])* pub $field: $ty, )*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self {
+                    $( $field: $default, )*
+                }
+            }
+        }
+
+        impl $crate::template::params::PuzzleParams for $name {
+            fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+                match name {
+                    $(
+                        stringify!($field) => {
+                            self.$field = value.parse().map_err(|e| {
+                                format!("invalid value `{value}` for parameter `{name}`: {e}")
+                            })?;
+                        }
+                    )*
+                    _ => return Err(format!("unknown parameter `{name}`")),
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::parse_params;
+
+    crate::params! {
+        struct Params {
+            size: isize = 70,
+            bytes: usize = 1024,
+        }
+    }
+
+    #[test]
+    fn overrides_defaults() {
+        let params: Params = parse_params("size: 6\n\nbytes: 12\n").unwrap();
+        assert_eq!((params.size, params.bytes), (6, 12));
+
+        let params: Params = parse_params("").unwrap();
+        assert_eq!((params.size, params.bytes), (70, 1024));
+    }
+
+    #[test]
+    fn rejects_invalid_params() {
+        assert!(parse_params::<Params>("size: -").is_err());
+        assert!(parse_params::<Params>("width: 11").is_err());
+        assert!(parse_params::<Params>("size").is_err());
+        assert!(parse_params::<()>("size: 6").is_err());
+        assert!(parse_params::<()>("").is_ok());
+    }
+}
@@ -0,0 +1,133 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[cfg(feature = "today")]
+use chrono::{Datelike, FixedOffset, Utc};
+
+use crate::template::{Day, Year};
+
+#[cfg(feature = "today")]
+const SERVER_UTC_OFFSET: i32 = -5;
+
+/// Identifies a puzzle by its year and day. The day is guaranteed to be part of advent in that year.
+///
+/// # Display
+/// This value displays as `<year>-<day>`, which is also the name of the puzzle's solution binary.
+///
+/// ```
+/// # use advent_of_code::template::{Day, Puzzle, Year};
+/// let puzzle = Puzzle::new(Year::new(2024).unwrap(), Day::new(8).unwrap()).unwrap();
+/// assert_eq!(puzzle.to_string(), "2024-08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Puzzle {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl Puzzle {
+    /// Creates a [`Puzzle`] if the day is part of advent in the given year,
+    /// returns [`None`] otherwise.
+    pub fn new(year: Year, day: Day) -> Option<Self> {
+        if !year.has_day(day) {
+            return None;
+        }
+        Some(Self { year, day })
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+}
+
+#[cfg(feature = "today")]
+impl Puzzle {
+    /// Returns the puzzle of the current day if advent is ongoing, `None` otherwise.
+    pub fn today() -> Option<Self> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let today = Utc::now().with_timezone(&offset);
+        if today.month() != 12 {
+            return None;
+        }
+
+        let year = Year::new(u16::try_from(today.year()).ok()?)?;
+        let day = Day::new(u8::try_from(today.day()).ok()?)?;
+        Self::new(year, day)
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Puzzle {
+    type Err = PuzzleFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, day) = s.split_once('-').ok_or(PuzzleFromStrError)?;
+        let year = year.parse().map_err(|_| PuzzleFromStrError)?;
+        let day = day.parse().map_err(|_| PuzzleFromStrError)?;
+        Self::new(year, day).ok_or(PuzzleFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Puzzle`].
+#[derive(Debug)]
+pub struct PuzzleFromStrError;
+
+impl Error for PuzzleFromStrError {}
+
+impl Display for PuzzleFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a puzzle in the format `<year>-<day>`, e.g. `2024-01`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Puzzle`] value in a const context.
+#[macro_export]
+macro_rules! puzzle {
+    ($year:expr, $day:expr) => {{
+        const _ASSERT: () = assert!(
+            $day <= $crate::year!($year).day_count(),
+            concat!(
+                "invalid day `",
+                $day,
+                "`, advent ",
+                $year,
+                " has fewer days"
+            ),
+        );
+        $crate::template::Puzzle::__new_unchecked($crate::year!($year), $crate::day!($day))
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Puzzle;
+    use crate::{day, puzzle, year};
+
+    #[test]
+    fn parses_puzzles() {
+        assert_eq!("2024-01".parse::<Puzzle>().unwrap(), puzzle!(2024, 1));
+        assert!("2025-13".parse::<Puzzle>().is_err());
+        assert!("01".parse::<Puzzle>().is_err());
+    }
+
+    #[test]
+    fn checks_days_of_year() {
+        assert_eq!(Puzzle::new(year!(2025), day!(12)), Some(puzzle!(2025, 12)));
+        assert_eq!(Puzzle::new(year!(2025), day!(13)), None);
+        assert_eq!(puzzle!(2024, 25).to_string(), "2024-25");
+    }
+}
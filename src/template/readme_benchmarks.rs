@@ -1,9 +1,11 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{fs, io, time::Duration};
 
-use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::allocations::format_bytes;
+use crate::template::config::{config, Config};
+use crate::template::timings::{Timing, Timings};
+use crate::template::{Puzzle, Year};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -25,11 +27,6 @@ pub struct TablePosition {
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     let matches: Vec<_> = readme.match_indices(MARKER).collect();
 
@@ -52,25 +49,45 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn construct_table(
+    config: &Config,
+    prefix: &str,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+) -> String {
+    let header = format!("{prefix} Benchmarks ({year})");
+
+    // the memory column is only shown once allocations were counted, see the `alloc-stats` feature.
+    let has_allocs = timings
+        .data
+        .iter()
+        .any(|timing| timing.part_1_allocs.is_some() || timing.part_2_allocs.is_some());
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    let mut lines: Vec<String> = vec![MARKER.into(), header, String::new()];
+
+    if has_allocs {
+        lines.push("| Day | Part 1 | Part 2 | Peak memory |".into());
+        lines.push("| :---: | :---: | :---: | :---: |".into());
+    } else {
+        lines.push("| Day | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---:  |".into());
+    }
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+        let path = config.bin_link(Puzzle::__new_unchecked(year, timing.day));
+        let memory = if has_allocs {
+            format!(" `{}` |", format_peak_bytes(&timing))
+        } else {
+            String::new()
+        };
+
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}]({}) | `{}` | `{}` |{memory}",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            format_part(timing.part_1_nanos, timing.part_1_timed_out),
+            format_part(timing.part_2_nanos, timing.part_2_timed_out)
         ));
     }
 
@@ -81,49 +98,114 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
+/// Format the timing of a part, `timeout` if it exceeded its time budget.
+fn format_part(nanos: Option<f64>, timed_out: bool) -> String {
+    if timed_out {
+        "timeout".into()
+    } else {
+        format_nanos(nanos)
+    }
+}
+
+/// Format the most memory either part of a day held at once, or `-` if no allocations were counted.
+fn format_peak_bytes(timing: &Timing) -> String {
+    [&timing.part_1_allocs, &timing.part_2_allocs]
+        .into_iter()
+        .flatten()
+        .map(|allocs| allocs.peak_bytes)
+        .max()
+        .map_or_else(|| "-".into(), format_bytes)
+}
+
+/// Format a duration like `74.1ms`, or `-` if the part has no timing.
+fn format_nanos(nanos: Option<f64>) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    nanos.map_or_else(
+        || "-".into(),
+        |nanos| format!("{:.1?}", Duration::from_nanos(nanos as u64)),
+    )
+}
+
+fn update_content(
+    config: &Config,
+    s: &mut String,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+    let table = construct_table(config, "##", year, timings, total_millis);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+/// Replace the benchmarking table in the readme of `aoc.toml` with the timings of a year.
+pub fn update(year: Year, timings: Timings) -> Result<(), Error> {
+    let config = config();
+    let mut readme = String::from_utf8_lossy(&fs::read(&config.readme)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
-    fs::write(path, &readme)?;
+    update_content(config, &mut readme, year, timings, total_millis)?;
+    fs::write(&config.readme, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{
+        day,
+        template::config::Config,
+        template::timings::{AllocStats, RunInfo, Timing, Timings},
+        year,
+    };
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    parse_nanos: None,
+                    part_1_nanos: Some(10_000_000_f64),
+                    part_2_nanos: Some(20_000_000_f64),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
+                    part_1_timed_out: false,
+                    part_2_timed_out: false,
                     total_nanos: 3e+10,
+                    run: RunInfo::default(),
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    parse_nanos: None,
+                    part_1_nanos: Some(30_000_000_f64),
+                    part_2_nanos: Some(40_000_000_f64),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
+                    part_1_timed_out: false,
+                    part_2_timed_out: false,
                     total_nanos: 7e+10,
+                    run: RunInfo::default(),
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
+                    parse_nanos: None,
+                    part_1_nanos: Some(40_000_000_f64),
+                    part_2_nanos: Some(50_000_000_f64),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
+                    part_1_timed_out: false,
+                    part_2_timed_out: false,
                     total_nanos: 9e+10,
+                    run: RunInfo::default(),
                 },
             ],
+            history: vec![],
         }
     }
 
@@ -131,28 +213,63 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &Config::default(),
+            &mut s,
+            year!(2024),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &Config::default(),
+            &mut s,
+            year!(2024),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &Config::default(),
+            &mut s,
+            year!(2024),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &Config::default(),
+            &mut s,
+            year!(2024),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
+        update_content(
+            &Config::default(),
+            &mut s,
+            year!(2024),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,18 +277,25 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &Config::default(),
+            &mut s,
+            year!(2024),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
         let expected = [
             "foo",
             "bar",
             "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "## Benchmarks (2024)",
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2024-01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2024-02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/2024-04.rs) | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +304,36 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn formats_timeouts() {
+        let mut timings = get_mock_timings();
+        timings.data[2].part_2_nanos = None;
+        timings.data[2].part_2_timed_out = true;
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&Config::default(), &mut s, year!(2024), timings, 190.0).unwrap();
+        assert!(s.contains("| [Day 4](./src/bin/2024-04.rs) | `40.0ms` | `timeout` |"));
+    }
+
+    #[test]
+    fn formats_peak_memory() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1_allocs = Some(AllocStats {
+            allocations: 12,
+            bytes: 4096,
+            peak_bytes: 2048,
+        });
+        timings.data[0].part_2_allocs = Some(AllocStats {
+            allocations: 3,
+            bytes: 1536,
+            peak_bytes: 1536,
+        });
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&Config::default(), &mut s, year!(2024), timings, 190.0).unwrap();
+        assert!(s.contains("| Day | Part 1 | Part 2 | Peak memory |"));
+        assert!(s.contains("| [Day 1](./src/bin/2024-01.rs) | `10.0ms` | `20.0ms` | `2.0 KiB` |"));
+        assert!(s.contains("| [Day 2](./src/bin/2024-02.rs) | `30.0ms` | `40.0ms` | `-` |"));
+    }
 }
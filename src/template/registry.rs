@@ -0,0 +1,57 @@
+/// Registry of all solutions, used to run them in-process.
+///
+/// `build.rs` includes every `src/bin/<year>-<day>.rs` that uses the [`solution!`](crate::solution) macro
+/// as a module of this one. The macro exposes the puzzle's solution as a `SOLUTION` constant, which is collected here.
+use crate::template::runner::{RunOptions, SolutionReport};
+use crate::template::Puzzle;
+
+/// Runs all parts of a solution against an input, with the given options of the runner.
+pub type SolutionRunner = fn(&str, &RunOptions) -> SolutionReport;
+
+/// Runs a single implementation of a part against an input, with the given options of the runner.
+pub type VariantRunner = fn(&str, &Variant, &RunOptions) -> SolutionReport;
+
+/// An implementation of a part, named after its function, e.g. `part_two` or `part_two_bitset`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Variant {
+    pub name: &'static str,
+    pub part: u8,
+}
+
+/// A single puzzle's solution.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub puzzle: Puzzle,
+    pub run: SolutionRunner,
+    /// The implementations of the parts: the solution's own first, then those registered with `variants = [...]`.
+    /// The parts of a combined `solve` are named `solve`.
+    pub variants: &'static [Variant],
+    pub run_variant: VariantRunner,
+}
+
+impl Solution {
+    /// The implementations of a part, the solution's own first.
+    pub fn variants_of(&self, part: u8) -> impl Iterator<Item = &'static Variant> {
+        self.variants
+            .iter()
+            .filter(move |variant| variant.part == part)
+    }
+}
+
+#[cfg(not(any(test, feature = "dhat-heap")))]
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+// NOTE: solution tests already run as part of their binaries, and every solution declares its own
+// global allocator when profiling with dhat. Skip registering them in both cases.
+#[cfg(any(test, feature = "dhat-heap"))]
+const SOLUTIONS: &[Solution] = &[];
+
+/// Returns every registered solution, ordered by year and day.
+pub fn all_solutions() -> &'static [Solution] {
+    SOLUTIONS
+}
+
+/// Returns the registered solution for a puzzle, if there is one.
+pub fn get_solution(puzzle: Puzzle) -> Option<&'static Solution> {
+    SOLUTIONS.iter().find(|solution| solution.puzzle == puzzle)
+}
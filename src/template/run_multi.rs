@@ -1,47 +1,500 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::HashSet,
+    io,
+    sync::mpsc::{Receiver, RecvTimeoutError},
+    time::{Duration, Instant},
+};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::answers::Answers;
+use crate::template::budgets::Budgets;
+use crate::template::runner::{
+    print_parse_report, print_report, Failure, ParseReport, PartReport, Progress, RunOptions,
+    SolutionReport, Stage,
+};
+use crate::template::{Day, Puzzle, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
 use super::{
     all_days,
-    timings::{Timing, Timings},
+    timings::{RunInfo, Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// The outcome of running the solution of a day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DayStatus {
+    /// Every part produced an answer.
+    Passed,
+    /// A part panicked or the solution did not run to completion.
+    Failed,
+    /// A part exceeded its time budget.
+    TimedOut,
+    /// The solution ran, but some part has no answer yet.
+    Unsolved,
+    /// The day has no solution or input.
+    Skipped,
+}
+
+impl DayStatus {
+    fn of(report: &SolutionReport) -> Self {
+        if report.is_failed() {
+            DayStatus::Failed
+        } else if report.is_timed_out() {
+            DayStatus::TimedOut
+        } else if !report.parts.is_empty() && report.parts.iter().all(|p| p.answer.is_some()) {
+            DayStatus::Passed
+        } else {
+            DayStatus::Unsolved
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            DayStatus::Passed => "✔ passed",
+            DayStatus::Failed => "✘ failed",
+            DayStatus::TimedOut => "⏱ timed out",
+            DayStatus::Unsolved => "unsolved",
+            DayStatus::Skipped => "skipped",
+        }
+    }
+}
+
+/// How the solutions of several days are run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MultiRunOptions {
+    /// Build the child processes in release mode. Only applies to isolated runs.
+    pub is_release: bool,
+    /// Bench the parts.
+    pub is_timed: bool,
+    /// Run each day in its own child process instead of in the current one.
+    pub is_isolated: bool,
+}
+
+/// The timings and per-day outcomes of running several days.
+pub struct MultiRun {
+    pub timings: Timings,
+    pub statuses: Vec<(Day, DayStatus)>,
+}
+
+impl MultiRun {
+    /// Whether any day failed.
+    pub fn has_failures(&self) -> bool {
+        self.statuses
+            .iter()
+            .any(|(_, status)| *status == DayStatus::Failed)
+    }
+}
+
+/// Run the solutions for a set of days of a year, collect their timings and print a summary of their outcomes.
+/// Solutions run in the current process, unless `options.is_isolated` is set. In that case, each day is run
+/// in its own child process, built in release mode if `options.is_release` is set.
+/// Parts that exceed their budget are stopped and recorded as timed out, see [`run_days`]. An in-process part that
+/// timed out keeps running on its abandoned thread, so the timings of the days after it are left out.
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    options: MultiRunOptions,
+    budgets: &Budgets,
+) -> MultiRun {
+    let results = run_days(year, days_to_run, options, budgets);
+
+    let abandoned_day = if options.is_isolated {
+        None
+    } else {
+        abandoned_day(&results)
+    };
+
+    // NOTE: failed parts have no answer, so they are not timed.
+    let timings = Timings {
+        data: results
+            .iter()
+            .filter(|(day, _)| abandoned_day.is_none_or(|abandoned| *day <= abandoned))
+            .map(|(day, report)| to_timing(report, *day))
+            .collect(),
+        history: vec![],
+    };
+
+    let total_millis = timings.total_millis();
+    println!("\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+
+    let statuses = get_statuses(year, days_to_run, &results);
+    print_summary(&statuses, &results, abandoned_day);
+
+    MultiRun { timings, statuses }
+}
+
+/// The first day with a timed out stage, if the run was in-process. Its solution keeps running on an abandoned
+/// thread, which competes with the days after it and skews their timings and allocations.
+fn abandoned_day(results: &[(Day, SolutionReport)]) -> Option<Day> {
+    results
+        .iter()
+        .find(|(_, report)| report.is_timed_out())
+        .map(|(day, _)| *day)
+}
+
+/// The status of every day that was asked to run, ordered by day. Days without a report were skipped.
+fn get_statuses(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    results: &[(Day, SolutionReport)],
+) -> Vec<(Day, DayStatus)> {
+    all_days(year)
+        .filter(|day| days_to_run.contains(day))
+        .map(|day| {
+            let status = results
+                .iter()
+                .find(|(d, _)| *d == day)
+                .map_or(DayStatus::Skipped, |(_, report)| DayStatus::of(report));
+            (day, status)
+        })
+        .collect()
+}
+
+/// Print a table of the status of each day, followed by the counts and the reasons of any failures.
+fn print_summary(
+    statuses: &[(Day, DayStatus)],
+    results: &[(Day, SolutionReport)],
+    abandoned_day: Option<Day>,
+) {
+    println!();
+    println!("{ANSI_BOLD}Summary:{ANSI_RESET}");
+    println!("| Day | Status |");
+    println!("| :---: | :--- |");
+
+    for (day, status) in statuses {
+        println!("| {day} | {} |", status.label());
+    }
+
+    let count = |status| statuses.iter().filter(|(_, s)| *s == status).count();
+    println!(
+        "{} passed, {} failed, {} timed out, {} unsolved, {} skipped",
+        count(DayStatus::Passed),
+        count(DayStatus::Failed),
+        count(DayStatus::TimedOut),
+        count(DayStatus::Unsolved),
+        count(DayStatus::Skipped)
+    );
+
+    for (day, report) in results {
+        if let Some(failure) = &report.failure {
+            print_failure(&format!("Day {day}"), failure);
+        }
+        if let Some(failure) = report.parse.as_ref().and_then(|p| p.failure.as_ref()) {
+            print_failure(&format!("Day {day} Parse"), failure);
+        }
+        for part in &report.parts {
+            if let Some(failure) = &part.failure {
+                print_failure(&format!("Day {day} Part {}", part.part), failure);
+            }
+        }
+    }
+
+    if let Some(day) = abandoned_day.filter(|day| results.iter().any(|(d, _)| d > day)) {
+        println!(
+            "⏱ Timings after day {day} are unreliable: its timed out part kept running in-process. They are not recorded, run with `--isolated` to time them."
+        );
+    }
+}
+
+/// Print a failure of the summary, followed by the line of the input it points at.
+fn print_failure(name: &str, failure: &Failure) {
+    println!("✘ {name}: {failure}");
+
+    if let Some(location) = &failure.location {
+        println!("{}", location.context());
+    }
+}
 
+/// Run the solutions for a set of days of a year and collect their reports, ordered by day.
+/// Days without a solution or input are left out.
+///
+/// Each stage of a day, i.e. its shared parse and its parts, may run for the day's budget. A child process that
+/// exceeds it is killed. An in-process solution can't be killed, it is abandoned on its thread instead and
+/// skips its remaining stages once the current one returns.
+pub fn run_days(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    options: MultiRunOptions,
+    budgets: &Budgets,
+) -> Vec<(Day, SolutionReport)> {
+    let mut results = Vec::with_capacity(days_to_run.len());
+
+    // NOTE: the answers only mark the results, a broken store leaves them unmarked.
+    let answers = Answers::read_from_file(year).ok();
     let mut need_space = false;
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
+    all_days(year)
         .filter(|day| days_to_run.contains(day))
         .for_each(|day| {
+            let puzzle = Puzzle::__new_unchecked(year, day);
+            let budget = budgets.for_day(day);
+
             if need_space {
                 println!();
             }
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
-
-            need_space = if output.is_empty() {
-                false
+            if options.is_isolated {
+                let report = child_commands::run_solution(
+                    puzzle,
+                    options.is_release,
+                    budget,
+                    answers.clone(),
+                )
+                .unwrap();
+
+                need_space =
+                    if report.parts.is_empty() && !report.is_failed() && !report.is_timed_out() {
+                        false
+                    } else {
+                        results.push((day, report));
+                        true
+                    }
             } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+                let run_options = RunOptions {
+                    answers: answers.clone(),
+                    ..RunOptions::benched(options.is_timed)
+                };
 
-                true
+                need_space = match in_process::run_solution(puzzle, run_options, budget) {
+                    None => false,
+                    Some(Ok(report)) => {
+                        results.push((day, report));
+                        true
+                    }
+                    Some(Err(e)) => {
+                        eprintln!("Could not read input for day {day}: {e}");
+                        true
+                    }
+                }
             }
         });
 
-    if true {
-        let timings = Timings { data: timings };
-        let total_millis = timings.total_millis();
-        println!(
-            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
-        );
-        Some(timings)
-    } else {
-        None
+    results
+}
+
+/// Collect the progress of a running solution into its report, until the solution is done or the current
+/// stage exceeds `budget`. In that case, the stage is recorded as timed out and `true` is returned, the caller
+/// has to stop the solution.
+fn watch_progress(
+    puzzle: Puzzle,
+    receiver: &Receiver<Progress>,
+    budget: Option<Duration>,
+) -> (SolutionReport, bool) {
+    let mut report = SolutionReport::default();
+    let mut deadline: Option<(Stage, Instant)> = None;
+
+    loop {
+        let progress = match deadline {
+            Some((_, at)) => receiver.recv_timeout(at.saturating_duration_since(Instant::now())),
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        match progress {
+            Ok(Progress::Started(stage)) => deadline = budget.map(|b| (stage, Instant::now() + b)),
+            Ok(Progress::Benching(_)) => deadline = None,
+            Ok(Progress::Parsed(parse)) => {
+                deadline = None;
+                report.parse = Some(parse);
+            }
+            Ok(Progress::Finished(part)) => {
+                deadline = None;
+                report.parts.push(part);
+            }
+            Err(RecvTimeoutError::Disconnected) => return (report, false),
+            Err(RecvTimeoutError::Timeout) => {
+                let (Some((stage, _)), Some(budget)) = (deadline, budget) else {
+                    continue;
+                };
+
+                match stage {
+                    Stage::Parse => {
+                        let parse = ParseReport {
+                            duration: budget,
+                            timed_out: true,
+                            ..ParseReport::unfinished(puzzle)
+                        };
+                        print_parse_report(&parse);
+                        report.parse = Some(parse);
+                    }
+                    Stage::Part(part) => {
+                        let part = PartReport {
+                            duration: budget,
+                            timed_out: true,
+                            ..PartReport::unfinished(puzzle, part)
+                        };
+                        print_report(&part, None);
+                        report.parts.push(part);
+                    }
+                }
+
+                return (report, true);
+            }
+        }
+    }
+}
+
+/// Solutions are compiled into the library and listed in the solution registry.
+/// This module encapsulates running them in the current process.
+pub mod in_process {
+    use std::{
+        io,
+        sync::{atomic::AtomicBool, atomic::Ordering, mpsc, Arc},
+        thread,
+        time::Duration,
+    };
+
+    use super::watch_progress;
+    use crate::template::registry::{get_solution, Variant};
+    use crate::template::runner::{watch, RunOptions, SolutionReport};
+    use crate::template::{try_read_file, Puzzle, ANSI_BOLD, ANSI_RESET};
+
+    /// Run the registered solution for a given puzzle against its puzzle input. Returns [`None`] if there is no
+    /// solution for the puzzle. With a `budget`, the solution runs on its own thread, which is abandoned if a stage
+    /// exceeds the budget.
+    pub fn run_solution(
+        puzzle: Puzzle,
+        options: RunOptions,
+        budget: Option<Duration>,
+    ) -> Option<Result<SolutionReport, io::Error>> {
+        get_solution(puzzle)?;
+
+        println!("{ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day);
+        println!("------");
+
+        match try_read_file("inputs", puzzle) {
+            Ok(input) => run_input(puzzle, input, options, budget).map(Ok),
+            Err(e) => Some(Err(e)),
+        }
+    }
+
+    /// Run the registered solution for a given puzzle against an input. Returns [`None`] if there is no solution
+    /// for the puzzle.
+    pub fn run_input(
+        puzzle: Puzzle,
+        input: String,
+        options: RunOptions,
+        budget: Option<Duration>,
+    ) -> Option<SolutionReport> {
+        let solution = get_solution(puzzle)?;
+        Some(run_within(puzzle, budget, move || {
+            (solution.run)(&input, &options)
+        }))
+    }
+
+    /// Run a variant of the registered solution for a given puzzle against an input. Returns [`None`] if there is no
+    /// solution for the puzzle.
+    pub fn run_variant(
+        puzzle: Puzzle,
+        input: String,
+        variant: &'static Variant,
+        budget: Option<Duration>,
+    ) -> Option<SolutionReport> {
+        let solution = get_solution(puzzle)?;
+        Some(run_within(puzzle, budget, move || {
+            (solution.run_variant)(&input, variant, &RunOptions::benched(true))
+        }))
+    }
+
+    /// Run a solution, on its own thread if it has a `budget`. The thread is abandoned if a stage exceeds the budget.
+    fn run_within(
+        puzzle: Puzzle,
+        budget: Option<Duration>,
+        run: impl FnOnce() -> SolutionReport + Send + 'static,
+    ) -> SolutionReport {
+        if budget.is_none() {
+            return run();
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        let cancelled = Arc::new(AtomicBool::new(false));
+
+        let handle = thread::spawn({
+            let cancelled = Arc::clone(&cancelled);
+            move || {
+                watch(sender, cancelled);
+                run()
+            }
+        });
+
+        let (report, timed_out) = watch_progress(puzzle, &receiver, budget);
+
+        if timed_out {
+            cancelled.store(true, Ordering::Relaxed);
+            return report;
+        }
+
+        handle.join().unwrap_or_else(|_| SolutionReport {
+            failure: Some("solution thread panicked".into()),
+            ..report
+        })
+    }
+}
+
+/// Collect the timings of a day from the report of its solution.
+pub fn to_timing(report: &SolutionReport, day: Day) -> Timing {
+    let mut timing = Timing {
+        day,
+        parse_nanos: None,
+        part_1_nanos: None,
+        part_2_nanos: None,
+        part_1_stats: None,
+        part_2_stats: None,
+        part_1_allocs: None,
+        part_2_allocs: None,
+        part_1_timed_out: false,
+        part_2_timed_out: false,
+        total_nanos: 0_f64,
+        run: RunInfo::default(),
+    };
+
+    if let Some(parse) = report
+        .parse
+        .as_ref()
+        .filter(|parse| !parse.timed_out && parse.failure.is_none())
+    {
+        let nanos = parse.duration.as_nanos() as f64;
+        timing.parse_nanos = Some(nanos);
+        timing.total_nanos += nanos;
     }
+
+    // NOTE: a timed out parse keeps both parts from running, they are recorded as timed out as well.
+    let parse_timed_out = report.parse.as_ref().is_some_and(|parse| parse.timed_out);
+    timing.part_1_timed_out = parse_timed_out;
+    timing.part_2_timed_out = parse_timed_out;
+
+    for report in report.parts.iter().filter(|report| report.timed_out) {
+        match report.part {
+            1 => timing.part_1_timed_out = true,
+            2 => timing.part_2_timed_out = true,
+            _ => {}
+        }
+    }
+
+    // NOTE: parts without an answer are not timed, neither are parts solved together with part 1.
+    for report in report
+        .parts
+        .iter()
+        .filter(|report| report.answer.is_some() && !report.shared)
+    {
+        let nanos = report.duration.as_nanos() as f64;
+
+        match report.part {
+            1 => {
+                timing.part_1_nanos = Some(nanos);
+                timing.part_1_stats = report.stats.clone();
+                timing.part_1_allocs = report.allocs.clone();
+            }
+            2 => {
+                timing.part_2_nanos = Some(nanos);
+                timing.part_2_stats = report.stats.clone();
+                timing.part_2_allocs = report.allocs.clone();
+            }
+            _ => continue,
+        }
+
+        timing.total_nanos += nanos;
+    }
+
+    timing
 }
 
 #[allow(dead_code)]
@@ -57,49 +510,88 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as parsing their JSON output.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::{Day, ANSI_BOLD, ANSI_RESET};
-    use regex::Regex;
+    use super::{watch_progress, Error};
+    use crate::template::allocations;
+    use crate::template::answers::Answers;
+    use crate::template::config::config;
+    use crate::template::runner::{print_parse_report, print_report, Progress, SolutionReport};
+    use crate::template::solution_args::{InputSource, SolutionArgs};
+    use crate::template::{data_file_path, Puzzle, ANSI_BOLD, ANSI_RESET};
     use std::{
         io::{BufRead, BufReader},
-        path::Path,
         process::{Command, Stdio},
+        str::FromStr,
+        sync::mpsc,
         thread,
+        time::Duration,
     };
+    use tinyjson::JsonValue;
+
+    /// Run the solution bin for a given puzzle against its puzzle input and collect its report. Its parts are checked
+    /// against `answers`. With a `budget`, the child process is killed if a stage exceeds it. Building the bin
+    /// doesn't count.
+    pub fn run_solution(
+        puzzle: Puzzle,
+        is_release: bool,
+        budget: Option<Duration>,
+        answers: Option<Answers>,
+    ) -> Result<SolutionReport, Error> {
+        // skip command invocation for puzzles that have not been scaffolded yet.
+        if !config().bin_path(puzzle).exists() {
+            return Ok(SolutionReport::default());
+        }
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        // skip days without input, same as when running in-process.
+        if !data_file_path("inputs", puzzle).exists() {
+            return Ok(SolutionReport::default());
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        println!("{ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day);
+        println!("------");
+
+        run_input(puzzle, &InputSource::Puzzle, is_release, budget, answers)
+    }
+
+    /// Run the solution bin for a given puzzle against an input and collect its report, like [`run_solution`].
+    /// Each run gets a process of its own, so that state kept by the solution, e.g. a global cache, can't leak from
+    /// one input into the next.
+    pub fn run_input(
+        puzzle: Puzzle,
+        input: &InputSource,
+        is_release: bool,
+        budget: Option<Duration>,
+        answers: Option<Answers>,
+    ) -> Result<SolutionReport, Error> {
+        let bin_name = puzzle.to_string();
+        let mut args = vec!["run", "--quiet", "--bin", &bin_name];
 
         if is_release {
             args.push("--release");
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        // count the allocations of the child as well.
+        if allocations::is_enabled() {
+            args.extend(["--features", "alloc-stats"]);
+        }
+
+        let input_args = SolutionArgs {
+            input: input.clone(),
+            part: None,
         }
+        .to_args();
+
+        args.extend(["--", "--format", "json"]);
+        args.extend(input_args.iter().map(String::as_str));
 
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward stderr, print reports as they arrive and pass through any other output.
 
         let mut cmd = Command::new("cargo")
             .args(&args)
+            .current_dir(&config().root)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()?;
@@ -107,142 +599,405 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
-
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-        println!("------");
-
-        let thread = thread::spawn(move || {
+        let stderr_thread = thread::spawn(move || {
             stderr.lines().for_each(|line| {
                 eprintln!("{}", line.unwrap());
             });
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
-        }
+        let (sender, receiver) = mpsc::channel();
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        let stdout_thread = thread::spawn(move || {
+            for line in stdout.lines() {
+                let line = line.unwrap();
 
-        Ok(output)
-    }
-
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
-
-        output
-            .iter()
-            .filter_map(|l| {
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    return None;
+                let progress = match parse_progress(&line) {
+                    Some(progress) => progress,
+                    None => {
+                        println!("{line}");
+                        continue;
+                    }
                 };
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+                match &progress {
+                    Progress::Finished(part) => print_report(part, answers.as_ref()),
+                    Progress::Parsed(parse) => print_parse_report(parse),
+                    Progress::Started(_) | Progress::Benching(_) => {}
                 }
 
-                timings.total_nanos += nanos;
-            });
+                // NOTE: the watcher is gone once the child timed out.
+                let _ = sender.send(progress);
+            }
+        });
 
-        timings
-    }
+        let (mut report, timed_out) = watch_progress(puzzle, &receiver, budget);
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
+        if timed_out {
+            cmd.kill()?;
+        }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        let re = Regex::new(r#"Part \d: .* \((\d+?(?:\.\d+)..?)"#).unwrap();
-        let caps = re.captures(line)?;
-        let str_timing = caps.get(1).unwrap().as_str();
+        stdout_thread.join().unwrap();
+        stderr_thread.join().unwrap();
+        let status = cmd.wait()?;
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+        // a failed part already explains the exit status, anything else crashed the solution outside its parts.
+        if !status.success() && !timed_out && !report.is_failed() {
+            report.failure = Some(format!("solution exited with {status}").into());
+        }
 
-        Some((str_timing, parsed_timing))
+        Ok(report)
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    /// Parse a line of solution output as progress, i.e. a stage starting or benching or a report.
+    /// Returns [`None`] for any other output.
+    pub fn parse_progress(line: &str) -> Option<Progress> {
+        let json = JsonValue::from_str(line).ok()?;
+        Progress::try_from(&json).ok()
     }
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::parse_progress;
+        use crate::puzzle;
+        use crate::template::runner::{ParseReport, PartReport, Progress, Stage};
+
+        fn parse_report(line: &str) -> Option<PartReport> {
+            match parse_progress(line)? {
+                Progress::Finished(report) => Some(report),
+                _ => None,
+            }
+        }
 
-        use crate::day;
+        fn parse_parse_report(line: &str) -> Option<ParseReport> {
+            match parse_progress(line)? {
+                Progress::Parsed(report) => Some(report),
+                _ => None,
+            }
+        }
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+        fn parses_reports() {
+            let report = parse_report(
+                r#"{"year":"2024","day":"01","part":1,"answer":"42","nanos":74130,"samples":1,"status":"solved","stats":null}"#,
+            )
+            .unwrap();
+            assert_eq!(report.puzzle, puzzle!(2024, 1));
+            assert_eq!(report.part, 1);
+            assert_eq!(report.answer, Some("42".into()));
+            assert_eq!(report.duration.as_nanos(), 74130);
+            assert_eq!(report.stats.is_none(), true);
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
+        fn parses_bench_statistics() {
+            let report = parse_report(
+                r#"{"year":"2024","day":"01","part":2,"answer":"10","nanos":100,"samples":10000,"status":"solved","stats":{"samples":10000,"mean_nanos":100,"median_nanos":90,"min_nanos":80,"std_dev_nanos":3.2}}"#,
+            )
+            .unwrap();
+            let stats = report.stats.unwrap();
+            assert_eq!(stats.samples, 10000);
+            assert_eq!(stats.median_nanos, 90_f64);
+            assert_eq!(stats.std_dev_nanos, 3.2_f64);
+        }
+
+        #[test]
+        fn parses_answers_that_look_like_output() {
+            let report = parse_report(
+                r#"{"year":"2024","day":"01","part":1,"answer":"Part 1: @ ( ) ms (2s @ 5 samples)\n#.#\n.#.","nanos":100,"samples":1,"status":"solved","stats":null}"#,
+            )
+            .unwrap();
+            assert_eq!(
+                report.answer,
+                Some("Part 1: @ ( ) ms (2s @ 5 samples)\n#.#\n.#.".into())
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(report.duration.as_nanos(), 100);
         }
 
         #[test]
         fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
+            let report = parse_report(
+                r#"{"year":"2024","day":"01","part":1,"answer":null,"nanos":100,"samples":1,"status":"unsolved","stats":null}"#,
+            )
+            .unwrap();
+            assert_eq!(report.answer.is_none(), true);
+        }
+
+        #[test]
+        fn parses_parse_reports() {
+            let line = r#"{"year":"2024","day":"05","stage":"parse","nanos":1200,"stats":null}"#;
+            let report = parse_parse_report(line).unwrap();
+            assert_eq!(report.puzzle, puzzle!(2024, 5));
+            assert_eq!(report.duration.as_nanos(), 1200);
+            assert_eq!(parse_report(line).is_none(), true);
+        }
+
+        #[test]
+        fn parses_shared_parts() {
+            let report = parse_report(
+                r#"{"year":"2024","day":"05","part":2,"answer":"123","nanos":0,"samples":1,"status":"solved","stats":null,"shared":true}"#,
+            )
+            .unwrap();
+            assert_eq!(report.shared, true);
+            assert_eq!(
+                parse_parse_report(
+                    r#"{"year":"2024","day":"05","part":2,"answer":"123","nanos":0}"#
+                )
+                .is_none(),
+                true
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[test]
+        fn parses_failed_parts() {
+            let report = parse_report(
+                r#"{"year":"2024","day":"01","part":1,"answer":null,"nanos":0,"samples":1,"status":"failed","stats":null,"failure":"panicked at src/bin/2024-01.rs:8:30: Invalid line"}"#,
+            )
+            .unwrap();
+            assert_eq!(report.answer.is_none(), true);
+            assert_eq!(
+                report.failure,
+                Some("panicked at src/bin/2024-01.rs:8:30: Invalid line".into())
+            );
+        }
+
+        #[test]
+        fn parses_progress() {
+            assert!(matches!(
+                parse_progress(
+                    r#"{"year":"2024","day":"06","progress":"started","stage":"part_2"}"#
+                ),
+                Some(Progress::Started(Stage::Part(2)))
+            ));
+            assert!(matches!(
+                parse_progress(
+                    r#"{"year":"2024","day":"06","progress":"benching","stage":"parse"}"#
+                ),
+                Some(Progress::Benching(Stage::Parse))
+            ));
+            assert!(parse_progress(
+                r#"{"year":"2024","day":"06","progress":"stopped","stage":"parse"}"#
+            )
+            .is_none());
+        }
+
+        #[test]
+        fn parses_timed_out_parts() {
+            let report = parse_report(
+                r#"{"year":"2024","day":"06","part":2,"answer":null,"nanos":10000000000,"samples":1,"status":"timeout","stats":null}"#,
+            )
+            .unwrap();
+            assert_eq!(report.timed_out, true);
+            assert_eq!(report.duration.as_secs(), 10);
+        }
+
+        #[test]
+        fn ignores_other_output() {
+            assert_eq!(parse_report("Part 1: 42 (74.13ns)").is_none(), true);
+            assert_eq!(parse_report("{}").is_none(), true);
+            assert_eq!(parse_report("").is_none(), true);
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use std::sync::mpsc;
+
+    use super::{abandoned_day, get_statuses, to_timing, watch_progress, DayStatus};
+    use crate::template::runner::{ParseReport, PartReport, Progress, SolutionReport, Stage};
+    use crate::template::Day;
+    use crate::{day, puzzle, year};
+
+    fn part(part: u8, answer: Option<&str>, micros: u64, shared: bool) -> PartReport {
+        PartReport {
+            puzzle: puzzle!(2024, 1),
+            part,
+            answer: answer.map(Into::into),
+            duration: Duration::from_micros(micros),
+            stats: None,
+            allocs: None,
+            shared,
+            failure: None,
+            timed_out: false,
+        }
+    }
+
+    #[test]
+    fn converts_part_reports() {
+        let report = SolutionReport {
+            parse: None,
+            parts: vec![part(1, Some("42"), 1500, false), part(2, None, 20, false)],
+            failure: None,
+        };
+
+        let timing = to_timing(&report, day!(1));
+        assert_eq!(timing.parse_nanos, None);
+        assert_eq!(timing.part_1_nanos, Some(1_500_000_f64));
+        assert_eq!(timing.part_2_nanos, None);
+        assert_eq!(timing.total_nanos, 1_500_000_f64);
+    }
+
+    #[test]
+    fn converts_parse_and_shared_reports() {
+        let report = SolutionReport {
+            parse: Some(ParseReport {
+                puzzle: puzzle!(2024, 1),
+                duration: Duration::from_micros(500),
+                stats: None,
+                failure: None,
+                timed_out: false,
+            }),
+            parts: vec![
+                part(1, Some("42"), 1500, false),
+                part(2, Some("7"), 0, true),
+            ],
+            failure: None,
+        };
+
+        let timing = to_timing(&report, day!(1));
+        assert_eq!(timing.parse_nanos, Some(500_000_f64));
+        assert_eq!(timing.part_1_nanos, Some(1_500_000_f64));
+        assert_eq!(timing.part_2_nanos, None);
+        assert_eq!(timing.total_nanos, 2_000_000_f64);
+    }
+
+    #[test]
+    fn finds_first_abandoned_day() {
+        let timed_out = PartReport {
+            timed_out: true,
+            ..part(2, None, 0, false)
+        };
+        let report = |parts| SolutionReport {
+            parts,
+            ..SolutionReport::default()
+        };
+
+        let mut results = vec![
+            (day!(1), report(vec![part(1, Some("1"), 1, false)])),
+            (day!(3), report(vec![part(1, Some("1"), 1, false)])),
+        ];
+        assert_eq!(abandoned_day(&results), None);
+
+        results.insert(
+            1,
+            (
+                day!(2),
+                report(vec![part(1, Some("1"), 1, false), timed_out]),
+            ),
+        );
+        assert_eq!(abandoned_day(&results), Some(day!(2)));
+    }
+
+    #[test]
+    fn classifies_days() {
+        let failed = PartReport {
+            failure: Some("panicked at src/bin/2024-03.rs:1:1: Invalid line".into()),
+            ..part(1, None, 0, false)
+        };
+
+        let results = vec![
+            (
+                day!(1),
+                SolutionReport {
+                    parts: vec![part(1, Some("1"), 1, false), part(2, Some("2"), 1, false)],
+                    ..SolutionReport::default()
+                },
+            ),
+            (
+                day!(2),
+                SolutionReport {
+                    parts: vec![part(1, Some("1"), 1, false), part(2, None, 1, false)],
+                    ..SolutionReport::default()
+                },
+            ),
+            (
+                day!(3),
+                SolutionReport {
+                    parts: vec![failed, part(2, Some("2"), 1, false)],
+                    ..SolutionReport::default()
+                },
+            ),
+            (
+                day!(5),
+                SolutionReport {
+                    failure: Some("solution exited with exit status: 101".into()),
+                    ..SolutionReport::default()
+                },
+            ),
+        ];
+
+        let days_to_run = (1..=5).map(|d| Day::new(d).unwrap()).collect();
+        let statuses = get_statuses(year!(2024), &days_to_run, &results);
+
+        assert_eq!(
+            statuses,
+            vec![
+                (day!(1), DayStatus::Passed),
+                (day!(2), DayStatus::Unsolved),
+                (day!(3), DayStatus::Failed),
+                (day!(4), DayStatus::Skipped),
+                (day!(5), DayStatus::Failed),
+            ]
+        );
+    }
+
+    #[test]
+    fn collects_progress_within_budget() {
+        let (sender, receiver) = mpsc::channel();
+        sender.send(Progress::Started(Stage::Part(1))).unwrap();
+        sender.send(Progress::Benching(Stage::Part(1))).unwrap();
+        sender
+            .send(Progress::Finished(part(1, Some("42"), 10, false)))
+            .unwrap();
+        drop(sender);
+
+        let (report, timed_out) =
+            watch_progress(puzzle!(2024, 1), &receiver, Some(Duration::from_secs(10)));
+        assert!(!timed_out);
+        assert_eq!(report.parts.len(), 1);
+        assert!(!report.is_timed_out());
+    }
+
+    #[test]
+    fn times_out_stages_over_budget() {
+        let (sender, receiver) = mpsc::channel();
+        sender
+            .send(Progress::Finished(part(1, Some("42"), 10, false)))
+            .unwrap();
+        sender.send(Progress::Started(Stage::Part(2))).unwrap();
+
+        let (report, timed_out) =
+            watch_progress(puzzle!(2024, 1), &receiver, Some(Duration::from_millis(10)));
+        assert!(timed_out);
+        assert_eq!(report.parts.len(), 2);
+        assert!(report.parts[1].timed_out);
+        assert_eq!(report.parts[1].duration, Duration::from_millis(10));
+
+        let timing = to_timing(&report, day!(1));
+        assert_eq!(timing.part_1_nanos, Some(10_000_f64));
+        assert_eq!(timing.part_2_nanos, None);
+        assert!(timing.part_2_timed_out);
+
+        let statuses = get_statuses(year!(2024), &[day!(1)].into(), &[(day!(1), report)]);
+        assert_eq!(statuses, vec![(day!(1), DayStatus::TimedOut)]);
+        drop(sender);
+    }
+
+    #[test]
+    fn times_out_parse_for_both_parts() {
+        let report = SolutionReport {
+            parse: Some(ParseReport {
+                duration: Duration::from_secs(1),
+                timed_out: true,
+                ..ParseReport::unfinished(puzzle!(2024, 1))
+            }),
+            ..SolutionReport::default()
+        };
+
+        let timing = to_timing(&report, day!(1));
+        assert!(timing.part_1_timed_out && timing.part_2_timed_out);
+        assert_eq!(timing.total_nanos, 0_f64);
     }
 }
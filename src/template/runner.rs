@@ -1,42 +1,867 @@
 /// Encapsulates code that interacts with solution functions.
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::error::Error;
 use std::fmt::Display;
-use std::process::Output;
-use std::time::{Duration, Instant};
-use std::{env, process};
+use std::hint::black_box;
+use std::io::{stdout, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc::Sender, Arc, Once};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use std::{cmp, env, process};
 
+use tinyjson::JsonValue;
+
+use crate::template::allocations::{self, format_bytes};
+use crate::template::answers::{AnswerCheck, Answers};
+use crate::template::aoc_client::{AocClient, AocClientError};
+use crate::template::input_error::{InputError, InputLocation};
+use crate::template::submissions::{Submission, Submissions, Verdict};
+use crate::template::timings::{AllocStats, BenchStats};
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_RESET};
+use crate::template::{Day, Puzzle, Year, ANSI_ITALIC, ANSI_RESET};
+
+/// Approximate time spent warming up before a part is sampled.
+const WARMUP_TIME: Duration = Duration::from_millis(100);
+/// Approximate time spent sampling a part.
+const BENCH_TIME: Duration = Duration::from_secs(1);
+const MIN_SAMPLES: u128 = 10;
+const MAX_SAMPLES: u128 = 10_000;
+
+/// The outcome of running a single solution part.
+#[derive(Clone, Debug)]
+pub struct PartReport {
+    pub puzzle: Puzzle,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// Duration of the run, or the mean duration if the part was benched.
+    pub duration: Duration,
+    pub stats: Option<BenchStats>,
+    /// Allocations of the part, if they are counted with the `alloc-stats` feature.
+    pub allocs: Option<AllocStats>,
+    /// Set if the answer was computed together with part 1 by a combined `solve`.
+    /// The duration of both parts is reported on part 1 then.
+    pub shared: bool,
+    /// Set if the part panicked or returned an error.
+    pub failure: Option<Failure>,
+    /// Set if the part exceeded its time budget and was stopped. The duration is the budget then.
+    pub timed_out: bool,
+}
+
+impl PartReport {
+    /// The report of a part that did not run to completion.
+    pub fn unfinished(puzzle: Puzzle, part: u8) -> Self {
+        PartReport {
+            puzzle,
+            part,
+            answer: None,
+            duration: Duration::ZERO,
+            stats: None,
+            allocs: None,
+            shared: false,
+            failure: None,
+            timed_out: false,
+        }
+    }
+}
+
+/// The outcome of parsing a puzzle's input once, to share it between the parts.
+#[derive(Clone, Debug)]
+pub struct ParseReport {
+    pub puzzle: Puzzle,
+    /// Duration of the parse, or the mean duration if it was benched.
+    pub duration: Duration,
+    pub stats: Option<BenchStats>,
+    /// Set if the parse panicked or returned an error. The parts don't run then.
+    pub failure: Option<Failure>,
+    /// Set if the parse exceeded its time budget and was stopped. The duration is the budget then.
+    pub timed_out: bool,
+}
+
+impl ParseReport {
+    /// The report of a parse that did not run to completion.
+    pub fn unfinished(puzzle: Puzzle) -> Self {
+        ParseReport {
+            puzzle,
+            duration: Duration::ZERO,
+            stats: None,
+            failure: None,
+            timed_out: false,
+        }
+    }
+}
+
+/// The outcome of running a puzzle's solution.
+#[derive(Clone, Debug, Default)]
+pub struct SolutionReport {
+    /// Only set if the solution parses its input once for both parts.
+    pub parse: Option<ParseReport>,
+    pub parts: Vec<PartReport>,
+    /// Set if the solution did not run to completion for reasons outside its parts, e.g. its process crashed.
+    pub failure: Option<Failure>,
+}
+
+impl SolutionReport {
+    /// Whether the solution, its parse or any of its parts failed.
+    pub fn is_failed(&self) -> bool {
+        self.failure.is_some()
+            || self
+                .parse
+                .as_ref()
+                .is_some_and(|parse| parse.failure.is_some())
+            || self.parts.iter().any(|part| part.failure.is_some())
+    }
+
+    /// Whether the parse or any of the parts exceeded its time budget.
+    pub fn is_timed_out(&self) -> bool {
+        self.parse.as_ref().is_some_and(|parse| parse.timed_out)
+            || self.parts.iter().any(|part| part.timed_out)
+    }
+}
+
+/// Why a stage of a solution failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Failure {
+    /// The panic message and its location in the source, or the returned error.
+    pub message: String,
+    /// Where in the input the error was found, if the stage returned an [`InputError`].
+    pub location: Option<InputLocation>,
+}
+
+impl Failure {
+    /// The failure of a stage that returned an error. Picks up the location of an [`InputError`] in its sources.
+    pub fn from_error(error: &(dyn Error + 'static)) -> Self {
+        Failure {
+            message: error.to_string(),
+            location: InputError::find(error).map(|e| e.location.clone()),
+        }
+    }
+}
+
+impl From<String> for Failure {
+    fn from(message: String) -> Self {
+        Failure {
+            message,
+            location: None,
+        }
+    }
+}
+
+impl From<&str> for Failure {
+    fn from(message: &str) -> Self {
+        Failure::from(message.to_string())
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// Return types of solution parts: `Option<T>` for parts that may not have an answer yet, and `Result<T, E>`
+/// for parts that can fail, e.g. on a broken input. Return an [`InputError`] to point at where the input broke.
+pub trait Answer {
+    fn to_answer(&self) -> Result<Option<String>, Failure>;
+}
+
+impl<T: Display> Answer for Option<T> {
+    fn to_answer(&self) -> Result<Option<String>, Failure> {
+        Ok(self.as_ref().map(ToString::to_string))
+    }
+}
+
+impl<T: Display, E: Error + 'static> Answer for Result<T, E> {
+    fn to_answer(&self) -> Result<Option<String>, Failure> {
+        match self {
+            Ok(answer) => Ok(Some(answer.to_string())),
+            Err(e) => Err(Failure::from_error(e)),
+        }
+    }
+}
+
+/// Adapt an infallible parse to the signature of fallible ones.
+pub fn infallible<T>(func: impl Fn(&str) -> T) -> impl Fn(&str) -> Result<T, Infallible> {
+    move |input| Ok(func(input))
+}
+
+/// A stage of running a solution that is subject to a time budget.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(u8),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => f.write_str("parse"),
+            Stage::Part(part) => write!(f, "part_{part}"),
+        }
+    }
+}
+
+impl FromStr for Stage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Stage::Parse),
+            _ => s
+                .strip_prefix("part_")
+                .and_then(|part| part.parse().ok())
+                .map(Stage::Part)
+                .ok_or(format!("unknown stage `{s}`")),
+        }
+    }
+}
+
+/// Progress of a running solution, which lets a watcher enforce time budgets.
+/// Only the first run of a stage counts toward its budget, benching it does not.
+#[derive(Clone, Debug)]
+pub enum Progress {
+    Started(Stage),
+    Benching(Stage),
+    Parsed(ParseReport),
+    Finished(PartReport),
+}
+
+/// Receives the progress of the solution running on the current thread, see [`watch`].
+struct Watcher {
+    sender: Sender<Progress>,
+    cancelled: Arc<AtomicBool>,
+}
+
+thread_local! {
+    static WATCHER: RefCell<Option<Watcher>> = const { RefCell::new(None) };
+}
+
+/// Report the progress of solutions running on the current thread to `sender`.
+/// Once `cancelled` is set, the remaining stages are skipped and nothing is printed anymore,
+/// so that a solution that exceeded its budget can be abandoned.
+pub fn watch(sender: Sender<Progress>, cancelled: Arc<AtomicBool>) {
+    WATCHER.with(|watcher| *watcher.borrow_mut() = Some(Watcher { sender, cancelled }));
+}
+
+fn is_cancelled() -> bool {
+    WATCHER.with(|watcher| {
+        watcher
+            .borrow()
+            .as_ref()
+            .is_some_and(|watcher| watcher.cancelled.load(Ordering::Relaxed))
+    })
+}
+
+/// Report progress to the watcher of the current thread. Starting and benching a stage is also printed in
+/// JSON output, so that the watcher of a child process can follow.
+fn report_progress(puzzle: Puzzle, progress: Progress, format: OutputFormat) {
+    if format == OutputFormat::Json {
+        if let Some((event, stage)) = match &progress {
+            Progress::Started(stage) => Some(("started", stage)),
+            Progress::Benching(stage) => Some(("benching", stage)),
+            _ => None,
+        } {
+            println!(
+                "{}",
+                progress_to_json(puzzle, event, *stage).stringify().unwrap()
+            );
+        }
+    }
+
+    WATCHER.with(|watcher| {
+        if let Some(watcher) = watcher.borrow().as_ref() {
+            // NOTE: the watcher is gone if it abandoned the solution, there is nobody left to tell.
+            let _ = watcher.sender.send(progress);
+        }
+    });
+}
+
+/// Output format of the solution runner, selected with `--format <human|json>`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable output, printed while the part runs.
+    #[default]
+    Human,
+    /// One JSON object per part, printed once the part has finished.
+    Json,
+}
+
+/// Options of the runner. A solution binary reads them from its command-line arguments once, in its `main`, and
+/// passes them down to its parts. Solutions that run in-process from another command use the defaults, since the
+/// arguments of the process belong to that command.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RunOptions {
+    pub format: OutputFormat,
+    /// The part whose answer is submitted, set with `--submit <1|2>`.
+    pub submit: Option<u8>,
+    /// Whether the parts are benched.
+    pub bench: bool,
+    /// The known-correct answers that the parts are checked against. Only set for runs against the puzzle input,
+    /// answers of examples and other inputs differ.
+    pub answers: Option<Answers>,
+}
+
+impl RunOptions {
+    /// The options of a run that is benched if `bench` is set, without any other options.
+    pub fn benched(bench: bool) -> Self {
+        RunOptions {
+            bench,
+            ..RunOptions::default()
+        }
+    }
+
+    /// Check the parts against the answer store of a year. Without a readable store, the parts are not checked.
+    pub fn check_answers(&mut self, year: Year) {
+        match Answers::read_from_file(year) {
+            Ok(answers) => self.answers = Some(answers),
+            Err(e) => eprintln!("Could not read the answers, they are not checked: {e}"),
+        }
+    }
+
+    /// Read the options from the command-line arguments, exiting if they are invalid. Release builds bench the parts.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().skip(1).collect();
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+        Self::parse(&args).unwrap_or_else(|e| {
+            eprintln!("Unexpected command-line input. {e}");
+            process::exit(1);
+        })
+    }
+
+    /// Parse the options, ignoring arguments that belong to the [solution](crate::template::solution_args).
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = RunOptions::benched(!cfg!(debug_assertions));
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--format" => {
+                    options.format = match args.next().map(String::as_str) {
+                        Some("human") => OutputFormat::Human,
+                        Some("json") => OutputFormat::Json,
+                        _ => return Err("Format: --format <human|json>".into()),
+                    };
+                }
+                "--submit" => {
+                    options.submit = match args.next().map(String::as_str) {
+                        Some("1") => Some(1),
+                        Some("2") => Some(2),
+                        _ => return Err("Format: cargo solve 1 --submit 1".into()),
+                    };
+                }
+                _ => {}
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+/// Run a solution part with the puzzle's parameters and print its result, with the options of the command line.
+pub fn run_part<I: Clone, P, T: Answer>(
+    func: impl Fn(I, &P) -> T,
+    input: I,
+    params: &P,
+    puzzle: Puzzle,
+    part: u8,
+) -> PartReport {
+    run_part_with(func, input, params, puzzle, part, &RunOptions::from_args())
+}
+
+/// Run a solution part with the puzzle's parameters and print its result, benching it if `options.bench` is set.
+/// A part that panics or returns an error is reported as failed.
+pub fn run_part_with<I: Clone, P, T: Answer>(
+    func: impl Fn(I, &P) -> T,
+    input: I,
+    params: &P,
+    puzzle: Puzzle,
+    part: u8,
+    options: &RunOptions,
+) -> PartReport {
+    let (format, bench) = (options.format, options.bench);
     let part_str = format!("Part {part}");
+    let stage = Stage::Part(part);
 
-    let (result, duration) = run_timed(func, input, |result| print_result(result, &part_str, ""));
+    if is_cancelled() {
+        return PartReport::unfinished(puzzle, part);
+    }
 
-    print_result(&result, &part_str, &format!(" ({duration:.1?})"));
+    report_progress(puzzle, Progress::Started(stage), format);
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    let func = |input| func(input, params);
+    let run = catch_panic(|| {
+        run_timed(func, input, bench, |result| {
+            if format == OutputFormat::Human && !is_cancelled() {
+                if let Ok(answer) = result.to_answer() {
+                    print_result(&answer, &part_str, "");
+                    print_benching(bench);
+                }
+            }
+            if bench {
+                report_progress(puzzle, Progress::Benching(stage), format);
+            }
+        })
+    });
+
+    let run =
+        run.map(|(result, duration, stats, allocs)| (result.to_answer(), duration, stats, allocs));
+
+    let report = match run {
+        Ok((Ok(answer), duration, stats, allocs)) => PartReport {
+            puzzle,
+            part,
+            answer,
+            duration,
+            stats,
+            allocs,
+            shared: false,
+            failure: None,
+            timed_out: false,
+        },
+        Ok((Err(failure), ..)) | Err(failure) => PartReport {
+            failure: Some(failure),
+            ..PartReport::unfinished(puzzle, part)
+        },
+    };
+
+    finish_part(&report, options);
+    report
+}
+
+/// Run a combined solution that computes the answers of both parts at once and print its results,
+/// benching it if `options.bench` is set. The duration is reported on part 1, part 2 is marked as shared.
+/// With `only`, just the answer of that part is reported, along with the duration.
+pub fn run_solve_with<I: Clone, P, A: Answer, B: Answer>(
+    func: impl Fn(I, &P) -> (A, B),
+    input: I,
+    params: &P,
+    puzzle: Puzzle,
+    only: Option<u8>,
+    options: &RunOptions,
+) -> Vec<PartReport> {
+    let (format, bench) = (options.format, options.bench);
+    let first_part = only.unwrap_or(1);
+    let stage = Stage::Part(first_part);
+
+    if is_cancelled() {
+        return [1, 2]
+            .into_iter()
+            .filter(|part| only.is_none_or(|only| only == *part))
+            .map(|part| PartReport::unfinished(puzzle, part))
+            .collect();
     }
+
+    report_progress(puzzle, Progress::Started(stage), format);
+
+    let func = |input| func(input, params);
+    let run = catch_panic(|| {
+        run_timed(func, input, bench, |(part_1, part_2)| {
+            if format == OutputFormat::Human && !is_cancelled() {
+                let answer = match first_part {
+                    1 => part_1.to_answer(),
+                    _ => part_2.to_answer(),
+                };
+                if let Ok(answer) = answer {
+                    print_result(&answer, &format!("Part {first_part}"), "");
+                    print_benching(bench);
+                }
+            }
+            if bench {
+                report_progress(puzzle, Progress::Benching(stage), format);
+            }
+        })
+    });
+
+    let ((part_1, part_2), duration, mut stats, mut allocs) = match run {
+        Ok(((part_1, part_2), duration, stats, allocs)) => (
+            (part_1.to_answer(), part_2.to_answer()),
+            duration,
+            stats,
+            allocs,
+        ),
+        Err(failure) => (
+            (Err(failure.clone()), Err(failure)),
+            Duration::ZERO,
+            None,
+            None,
+        ),
+    };
+
+    let reports: Vec<PartReport> = [(1, part_1), (2, part_2)]
+        .into_iter()
+        .filter(|(part, _)| only.is_none_or(|only| only == *part))
+        .map(|(part, answer)| to_part_report(puzzle, part, answer))
+        .enumerate()
+        .map(|(i, report)| match i {
+            // the first reported part carries the duration and allocations of the combined solve.
+            0 => PartReport {
+                duration,
+                stats: stats.take(),
+                allocs: allocs.take(),
+                ..report
+            },
+            _ => PartReport {
+                shared: true,
+                ..report
+            },
+        })
+        .collect();
+
+    for report in &reports {
+        finish_part(report, options);
+    }
+
+    reports
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Clone, T>(func: impl Fn(I) -> T, input: I, hook: impl Fn(&T)) -> (T, Duration) {
-    let timer = Instant::now();
-    let result = {
-        let input = input.clone();
+/// The report of a part computed by a combined `solve`, without its timing.
+fn to_part_report(puzzle: Puzzle, part: u8, answer: Result<Option<String>, Failure>) -> PartReport {
+    match answer {
+        Ok(answer) => PartReport {
+            answer,
+            ..PartReport::unfinished(puzzle, part)
+        },
+        Err(failure) => PartReport {
+            failure: Some(failure),
+            ..PartReport::unfinished(puzzle, part)
+        },
+    }
+}
+
+/// Parse the input of a solution once, so that its parts can share the result, and print the parse time.
+/// The parse is benched if `options.bench` is set. Returns [`None`] instead of the parsed input if the parse
+/// panicked or returned an error. Infallible parses are adapted with [`infallible`].
+pub fn run_parse_with<T, E: Error + 'static>(
+    func: impl Fn(&str) -> Result<T, E>,
+    input: &str,
+    puzzle: Puzzle,
+    options: &RunOptions,
+) -> (Option<T>, ParseReport) {
+    let (format, bench) = (options.format, options.bench);
+
+    if is_cancelled() {
+        return (None, ParseReport::unfinished(puzzle));
+    }
+
+    report_progress(puzzle, Progress::Started(Stage::Parse), format);
+
+    let run = catch_panic(|| {
+        run_timed(func, input, bench, |_| {
+            if format == OutputFormat::Human && !is_cancelled() {
+                print!("Parse:");
+                print_benching(bench);
+            }
+            if bench {
+                report_progress(puzzle, Progress::Benching(Stage::Parse), format);
+            }
+        })
+    });
+
+    let run = run.and_then(|(parsed, duration, stats, _)| match parsed {
+        Ok(parsed) => Ok((parsed, duration, stats)),
+        Err(e) => Err(Failure::from_error(&e)),
+    });
+
+    let (parsed, report) = match run {
+        Ok((parsed, duration, stats)) => (
+            Some(parsed),
+            ParseReport {
+                puzzle,
+                duration,
+                stats,
+                failure: None,
+                timed_out: false,
+            },
+        ),
+        Err(failure) => (
+            None,
+            ParseReport {
+                failure: Some(failure),
+                ..ParseReport::unfinished(puzzle)
+            },
+        ),
+    };
+
+    if is_cancelled() {
+        return (None, report);
+    }
+
+    match format {
+        OutputFormat::Human => print_parse_report(&report),
+        OutputFormat::Json => println!("{}", JsonValue::from(&report).stringify().unwrap()),
+    }
+
+    report_progress(puzzle, Progress::Parsed(report.clone()), format);
+
+    (parsed, report)
+}
+
+fn print_benching(bench: bool) {
+    if bench {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
+}
+
+/// Print the report of a finished part and submit its answer if requested.
+fn finish_part(report: &PartReport, options: &RunOptions) {
+    if is_cancelled() {
+        return;
+    }
+
+    match options.format {
+        OutputFormat::Human => print_report(report, options.answers.as_ref()),
+        OutputFormat::Json => println!("{}", JsonValue::from(report).stringify().unwrap()),
+    }
+
+    report_progress(
+        report.puzzle,
+        Progress::Finished(report.clone()),
+        options.format,
+    );
+
+    if let (Some(answer), Some(part)) = (&report.answer, options.submit) {
+        if part == report.part {
+            submit_result(answer, report.puzzle, part, options.format);
+        }
+    }
+}
+
+/// Print the human-readable line of a parse.
+pub fn print_parse_report(report: &ParseReport) {
+    if let Some(failure) = &report.failure {
+        print_failure("Parse", failure);
+        return;
+    }
+
+    if report.timed_out {
+        print_timeout("Parse", report.duration);
+        return;
+    }
+
+    let duration_str = match &report.stats {
+        Some(stats) => format_stats(stats),
+        None => format!("{:.1?}", report.duration),
+    };
+
+    print!("\r");
+    println!("Parse: ({duration_str})            ");
+}
+
+/// Print the final, human-readable result line of a part.
+/// The answer is marked with ✔ or ✘ if `answers` has a known-correct answer for the part.
+pub fn print_report(report: &PartReport, answers: Option<&Answers>) {
+    if let Some(failure) = &report.failure {
+        print_failure(&format!("Part {}", report.part), failure);
+        return;
+    }
+
+    if report.timed_out {
+        print_timeout(&format!("Part {}", report.part), report.duration);
+        return;
+    }
+
+    let duration_str = match &report.stats {
+        _ if report.shared => "with part 1".into(),
+        Some(stats) => format_stats(stats),
+        None => format!("{:.1?}", report.duration),
+    };
+
+    let allocs_str = match &report.allocs {
+        Some(allocs) => format!(", {}", format_allocs(allocs)),
+        None => String::new(),
+    };
 
+    let check = answers.map_or(AnswerCheck::Unknown, |answers| {
+        answers.check(report.puzzle.day, report.part, report.answer.as_deref())
+    });
+
+    let check_str = match check {
+        AnswerCheck::Correct => " ✔".into(),
+        AnswerCheck::Incorrect { expected } => format!(" ✘ (expected {expected})"),
+        AnswerCheck::Unknown => String::new(),
+    };
+
+    print_result(
+        &report.answer,
+        &format!("Part {}", report.part),
+        &format!(" ({duration_str}{allocs_str}){check_str}"),
+    );
+}
+
+/// Format the allocations of a part as e.g. `1204 allocations, 1.5 MiB peak`.
+fn format_allocs(allocs: &AllocStats) -> String {
+    format!(
+        "{} allocations, {} peak",
+        allocs.allocations,
+        format_bytes(allocs.peak_bytes)
+    )
+}
+
+/// Print a failure, followed by the line of the input it points at.
+fn print_failure(name: &str, failure: &Failure) {
+    print!("\r");
+    println!("{name}: ✘ {ANSI_BOLD}{failure}{ANSI_RESET}");
+
+    if let Some(location) = &failure.location {
+        println!("{}", location.context());
+    }
+}
+
+fn print_timeout(name: &str, budget: Duration) {
+    print!("\r");
+    println!("{name}: ⏱ {ANSI_BOLD}timed out after {budget:.1?}{ANSI_RESET}");
+}
+
+thread_local! {
+    /// Whether the current thread is inside [`catch_panic`].
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// The message and location of the last panic caught by [`catch_panic`].
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Install a panic hook that records panics of threads inside [`catch_panic`] instead of printing them, so that they
+/// are reported once, as the failure of the part. Panics anywhere else go to the previous hook.
+///
+/// NOTE: the hook is installed once for the whole process. Swapping it per call would let threads that overlap, like
+/// parts abandoned after a timeout, restore each other's hooks in the wrong order.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let previous_hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING.with(Cell::get) {
+                previous_hook(info);
+                return;
+            }
+
+            let message = info
+                .payload()
+                .downcast_ref::<&str>()
+                .map(ToString::to_string)
+                .or_else(|| info.payload().downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "Box<dyn Any>".into());
+
+            let failure = match info.location() {
+                Some(location) => format!("panicked at {location}: {message}"),
+                None => format!("panicked: {message}"),
+            };
+
+            LAST_PANIC.with(|last| *last.borrow_mut() = Some(failure));
+        }));
+    });
+}
+
+/// Run a function, catching any panic. Returns the panic message and location if it panicked.
+fn catch_panic<T>(func: impl FnOnce() -> T) -> Result<T, Failure> {
+    install_panic_hook();
+
+    let was_catching = CATCHING.with(|catching| catching.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+    CATCHING.with(|catching| catching.set(was_catching));
+
+    result.map_err(|_| {
+        LAST_PANIC
+            .with(|last| last.borrow_mut().take())
+            .unwrap_or_else(|| "panicked".into())
+            .into()
+    })
+}
+
+/// Run a solution part. The behavior differs depending on whether the part is benched:
+///  1. if not, the function is executed once.
+///  2. if so, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// Returns the result of the first execution, the duration to report (the mean if benched),
+/// if benched, the sample statistics and, if counted, the allocations of the first execution.
+fn run_timed<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    bench: bool,
+    hook: impl Fn(&T),
+) -> (T, Duration, Option<BenchStats>, Option<AllocStats>) {
+    let first_input = input.clone();
+    let timer = Instant::now();
+    let (result, allocs) = allocations::measure(|| {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
-    };
+        func(first_input)
+    });
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    (result, base_time)
+    // NOTE: benching under dhat would pollute the heap profile with every sample run.
+    if !bench || cfg!(feature = "dhat-heap") || is_cancelled() {
+        return (result, base_time, None, allocs);
+    }
+
+    match bench_part(func, input, &base_time) {
+        Some(stats) => {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let mean = Duration::from_nanos(stats.mean_nanos as u64);
+            (result, mean, Some(stats), allocs)
+        }
+        None => (result, base_time, None, allocs),
+    }
+}
+
+/// Warm up and sample a solution part.
+/// Fast parts are warmed up for [`WARMUP_TIME`] first, slower parts treat the initial run as warmup.
+/// The sample count is derived from the estimated run time so that sampling takes approx. [`BENCH_TIME`].
+fn bench_part<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+) -> Option<BenchStats> {
+    let mut estimate = *base_time;
+
+    if *base_time < WARMUP_TIME {
+        let timer = Instant::now();
+        let mut iterations: u32 = 0;
+
+        while timer.elapsed() < WARMUP_TIME {
+            black_box(func(black_box(input.clone())));
+            iterations += 1;
+        }
+
+        estimate = timer.elapsed() / iterations;
+    }
+
+    let bench_iterations =
+        (BENCH_TIME.as_nanos() / cmp::max(estimate.as_nanos(), 1)).clamp(MIN_SAMPLES, MAX_SAMPLES);
+
+    let mut timers: Vec<Duration> = Vec::with_capacity(bench_iterations as usize);
+
+    for _ in 0..bench_iterations {
+        let input = input.clone();
+        let timer = Instant::now();
+        black_box(func(black_box(input)));
+        timers.push(timer.elapsed());
+    }
+
+    BenchStats::from_samples(&timers)
+}
+
+/// Format benchmark statistics as e.g. `74.1ns @ 10000 samples, median 72.0ns, min 70.0ns, σ 3.2ns`.
+fn format_stats(stats: &BenchStats) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let to_duration = |nanos: f64| Duration::from_nanos(nanos as u64);
+
+    format!(
+        "{:.1?} @ {} samples, median {:.1?}, min {:.1?}, σ {:.1?}",
+        to_duration(stats.mean_nanos),
+        stats.samples,
+        to_duration(stats.median_nanos),
+        to_duration(stats.min_nanos),
+        to_duration(stats.std_dev_nanos),
+    )
 }
 
 fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
@@ -74,41 +899,573 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
+/// Try to submit the answer of a part, picked with `--submit`, if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+///  2. a session cookie is configured (see [`AocClient::from_env`]).
+///  3. the answer is not ruled out by earlier submissions (see [`Submissions::check`]).
+///
+/// The solution's `main` already refused to submit answers that were not computed from the puzzle input.
+/// Messages go to stderr in JSON output, so that stdout stays machine-readable.
 fn submit_result<T: Display>(
     result: T,
-    day: Day,
+    puzzle: Puzzle,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+    format: OutputFormat,
+) -> Option<Result<Verdict, AocClientError>> {
+    let client = AocClient::from_env();
 
-    if !args.contains(&"--submit".into()) {
-        return None;
-    }
+    let answer = result.to_string();
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+    // NOTE: without the ledger, known-wrong answers would be submitted again and storing it would lose the history.
+    let (mut answers, mut submissions) = match (
+        Answers::read_from_file(puzzle.year),
+        Submissions::read_from_file(puzzle.year),
+    ) {
+        (Ok(answers), Ok(submissions)) => (answers, submissions),
+        (Err(e), _) | (_, Err(e)) => {
+            eprintln!("Refusing to submit {answer}, earlier submissions can't be read: {e}");
+            process::exit(1);
+        }
+    };
+
+    if let Err(refusal) = submissions.check(puzzle.day, part, &answer) {
+        eprintln!("Refusing to submit {answer}: {refusal}");
         process::exit(1);
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    print_status(format, "Submitting result...");
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    let verdict = match client.submit(puzzle, part, &answer) {
+        Ok(message) => {
+            print_status(format, &message);
+            Verdict::from_response(&message)
+        }
+        Err(AocClientError::RateLimited(message)) => {
+            print_status(format, &message);
+            Verdict::RateLimited
+        }
+        Err(e) => {
+            eprintln!("Failed to submit answer: {e}");
+            return Some(Err(e));
+        }
     };
 
-    if part_submit != part {
-        return None;
+    record_submission(
+        &mut submissions,
+        &mut answers,
+        puzzle,
+        part,
+        answer,
+        verdict,
+        format,
+    );
+
+    Some(Ok(verdict))
+}
+
+/// Print a status message of a submission, to stderr in JSON output.
+fn print_status(format: OutputFormat, message: &str) {
+    match format {
+        OutputFormat::Human => println!("{message}"),
+        OutputFormat::Json => eprintln!("{message}"),
     }
+}
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+/// Record a submission and its verdict in the ledger. Correct answers are added to the answer store.
+fn record_submission(
+    submissions: &mut Submissions,
+    answers: &mut Answers,
+    puzzle: Puzzle,
+    part: u8,
+    answer: String,
+    verdict: Verdict,
+    format: OutputFormat,
+) {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+
+    if verdict == Verdict::Correct {
+        answers.set(puzzle.day, part, &answer);
+
+        if let Err(e) = answers.store_file(puzzle.year) {
+            eprintln!("Failed to store answer: {e}");
+        }
+    }
+
+    submissions.record(Submission {
+        day: puzzle.day,
+        part,
+        answer,
+        timestamp,
+        verdict,
+    });
+
+    match submissions.store_file(puzzle.year) {
+        Ok(()) => print_status(format, &format!("Recorded submission as {verdict}.")),
+        Err(e) => eprintln!("Failed to record submission: {e}"),
     }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartReport> for JsonValue {
+    fn from(value: &PartReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "year".into(),
+            JsonValue::String(value.puzzle.year.to_string()),
+        );
+        map.insert(
+            "day".into(),
+            JsonValue::String(value.puzzle.day.to_string()),
+        );
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "answer".into(),
+            value
+                .answer
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "nanos".into(),
+            JsonValue::Number(value.duration.as_nanos() as f64),
+        );
+        map.insert(
+            "samples".into(),
+            JsonValue::Number(value.stats.as_ref().map_or(1, |s| s.samples) as f64),
+        );
+        map.insert(
+            "status".into(),
+            JsonValue::String(
+                if value.failure.is_some() {
+                    "failed"
+                } else if value.timed_out {
+                    "timeout"
+                } else if value.answer.is_some() {
+                    "solved"
+                } else {
+                    "unsolved"
+                }
+                .into(),
+            ),
+        );
+        insert_failure(&mut map, value.failure.as_ref());
+        map.insert(
+            "stats".into(),
+            value
+                .stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+        map.insert(
+            "allocs".into(),
+            value
+                .allocs
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+        map.insert("shared".into(), JsonValue::Boolean(value.shared));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartReport {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part report to be a JSON object.")?;
+
+        let year = json
+            .get("year")
+            .and_then(|v| v.get::<String>())
+            .and_then(|year| Year::from_str(year).ok())
+            .ok_or("Expected report.year to be a Year struct.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected report.day to be a Day struct.")?;
+
+        let puzzle =
+            Puzzle::new(year, day).ok_or("Expected report.day to be a day of report.year.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .and_then(|&part| u8::try_from(part as i64).ok())
+            .ok_or("Expected report.part to be a part number.")?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected report.answer to be null or string.")?;
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected report.nanos to be a number.")?;
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+        let stats = match json.get("stats") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => Some(BenchStats::try_from(v)?),
+        };
+
+        let allocs = match json.get("allocs") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => Some(AllocStats::try_from(v)?),
+        };
+
+        let shared = match json.get("shared") {
+            None => false,
+            Some(v) => *v
+                .get::<bool>()
+                .ok_or("Expected report.shared to be a boolean.")?,
+        };
+
+        Ok(PartReport {
+            puzzle,
+            part,
+            answer: answer.cloned(),
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            duration: Duration::from_nanos(nanos as u64),
+            stats,
+            allocs,
+            shared,
+            failure: get_failure(json)?,
+            timed_out: json.get("status").and_then(|v| v.get::<String>())
+                == Some(&"timeout".to_string()),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&ParseReport> for JsonValue {
+    fn from(value: &ParseReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "year".into(),
+            JsonValue::String(value.puzzle.year.to_string()),
+        );
+        map.insert(
+            "day".into(),
+            JsonValue::String(value.puzzle.day.to_string()),
+        );
+        map.insert("stage".into(), JsonValue::String("parse".into()));
+        map.insert("timed_out".into(), JsonValue::Boolean(value.timed_out));
+        insert_failure(&mut map, value.failure.as_ref());
+        map.insert(
+            "nanos".into(),
+            JsonValue::Number(value.duration.as_nanos() as f64),
+        );
+        map.insert(
+            "stats".into(),
+            value
+                .stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for ParseReport {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected parse report to be a JSON object.")?;
+
+        if json.get("stage").and_then(|v| v.get::<String>()) != Some(&"parse".to_string()) {
+            return Err("Expected report.stage to be \"parse\".".into());
+        }
+
+        let year = json
+            .get("year")
+            .and_then(|v| v.get::<String>())
+            .and_then(|year| Year::from_str(year).ok())
+            .ok_or("Expected report.year to be a Year struct.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected report.day to be a Day struct.")?;
+
+        let puzzle =
+            Puzzle::new(year, day).ok_or("Expected report.day to be a day of report.year.")?;
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected report.nanos to be a number.")?;
+
+        let stats = match json.get("stats") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => Some(BenchStats::try_from(v)?),
+        };
+
+        Ok(ParseReport {
+            puzzle,
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            duration: Duration::from_nanos(nanos as u64),
+            stats,
+            failure: get_failure(json)?,
+            timed_out: json
+                .get("timed_out")
+                .and_then(|v| v.get::<bool>())
+                .copied()
+                .unwrap_or_default(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn progress_to_json(puzzle: Puzzle, event: &str, stage: Stage) -> JsonValue {
+    let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+    map.insert("year".into(), JsonValue::String(puzzle.year.to_string()));
+    map.insert("day".into(), JsonValue::String(puzzle.day.to_string()));
+    map.insert("progress".into(), JsonValue::String(event.into()));
+    map.insert("stage".into(), JsonValue::String(stage.to_string()));
+
+    JsonValue::Object(map)
+}
+
+impl TryFrom<&JsonValue> for Progress {
+    type Error = String;
+
+    /// Read the progress of a solution from its JSON output. Reports are read as finished stages.
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected progress to be a JSON object.")?;
+
+        let Some(event) = json.get("progress") else {
+            return PartReport::try_from(value)
+                .map(Progress::Finished)
+                .or_else(|_| ParseReport::try_from(value).map(Progress::Parsed));
+        };
+
+        let stage = json
+            .get("stage")
+            .and_then(|v| v.get::<String>())
+            .and_then(|stage| Stage::from_str(stage).ok())
+            .ok_or("Expected progress.stage to be a stage.")?;
+
+        match event.get::<String>().map(String::as_str) {
+            Some("started") => Ok(Progress::Started(stage)),
+            Some("benching") => Ok(Progress::Benching(stage)),
+            _ => Err("Expected progress.progress to be \"started\" or \"benching\".".into()),
+        }
+    }
+}
+
+/// Write the failure of a report as its message and, if it points at the input, the location.
+fn insert_failure(map: &mut HashMap<String, JsonValue>, failure: Option<&Failure>) {
+    map.insert(
+        "failure".into(),
+        failure.map_or(JsonValue::Null, |f| JsonValue::String(f.message.clone())),
+    );
+
+    let location = failure.and_then(|f| f.location.as_ref());
+    map.insert(
+        "location".into(),
+        location.map_or(JsonValue::Null, JsonValue::from),
+    );
+}
+
+/// Read the failure of a report. It is optional, reports of solutions built before it was introduced have none.
+fn get_failure(json: &HashMap<String, JsonValue>) -> Result<Option<Failure>, String> {
+    let message = match json.get("failure") {
+        None | Some(JsonValue::Null) => return Ok(None),
+        Some(v) => v
+            .get::<String>()
+            .cloned()
+            .ok_or("Expected report.failure to be null or string.")?,
+    };
+
+    let location = match json.get("location") {
+        None | Some(JsonValue::Null) => None,
+        Some(v) => Some(InputLocation::try_from(v)?),
+    };
+
+    Ok(Some(Failure { message, location }))
+}
+
+impl From<&InputLocation> for JsonValue {
+    fn from(value: &InputLocation) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("line".into(), JsonValue::Number(value.line as f64));
+        map.insert(
+            "column".into(),
+            value
+                .column
+                .map_or(JsonValue::Null, |column| JsonValue::Number(column as f64)),
+        );
+        map.insert("text".into(), JsonValue::String(value.text.clone()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for InputLocation {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected report.location to be a JSON object.")?;
+
+        let line = json
+            .get("line")
+            .and_then(|v| v.get::<f64>())
+            .map(|&line| line as usize)
+            .ok_or("Expected location.line to be a number.")?;
+
+        let column = match json.get("column") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => Some(
+                v.get::<f64>()
+                    .map(|&column| column as usize)
+                    .ok_or("Expected location.column to be null or a number.")?,
+            ),
+        };
+
+        let text = json
+            .get("text")
+            .and_then(|v| v.get::<String>())
+            .cloned()
+            .ok_or("Expected location.text to be a string.")?;
+
+        Ok(InputLocation { line, column, text })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::num::ParseIntError;
+    use std::str::FromStr;
+
+    use tinyjson::JsonValue;
+
+    use super::{catch_panic, Answer, Failure, OutputFormat, PartReport, RunOptions};
+    use crate::puzzle;
+    use crate::template::input_error::InputError;
+
+    fn parse_options(args: &[&str]) -> Result<RunOptions, String> {
+        let args: Vec<String> = args.iter().map(ToString::to_string).collect();
+        RunOptions::parse(&args)
+    }
+
+    #[test]
+    fn parses_run_options() {
+        let options = parse_options(&["--example", "--format", "json", "--submit", "2"]).unwrap();
+        assert_eq!(options.format, OutputFormat::Json);
+        assert_eq!(options.submit, Some(2));
+
+        let options = parse_options(&["--input", "-"]).unwrap();
+        assert_eq!(options.format, OutputFormat::Human);
+        assert_eq!(options.submit, None);
+
+        assert!(parse_options(&["--format", "xml"]).is_err());
+        assert!(parse_options(&["--submit"]).is_err());
+        assert!(parse_options(&["--submit", "3"]).is_err());
+    }
+
+    #[test]
+    fn catches_panics() {
+        assert_eq!(catch_panic(|| 42), Ok(42));
+
+        let failure = catch_panic(|| -> u32 { panic!("Invalid line") }).unwrap_err();
+        assert!(failure
+            .message
+            .starts_with("panicked at src/template/runner.rs:"));
+        assert!(failure.message.ends_with(": Invalid line"));
+
+        let failure = catch_panic(|| "x".parse::<u32>().unwrap()).unwrap_err();
+        assert!(failure.message.contains("ParseIntError"));
+    }
+
+    #[test]
+    fn catches_panics_of_overlapping_threads() {
+        let threads: Vec<_> = (0..4)
+            .map(|i| {
+                std::thread::spawn(move || {
+                    catch_panic(|| -> u32 {
+                        std::thread::sleep(std::time::Duration::from_millis(10 * (4 - i)));
+                        panic!("thread {i}")
+                    })
+                })
+            })
+            .collect();
+
+        for (i, thread) in threads.into_iter().enumerate() {
+            let failure = thread.join().unwrap().unwrap_err();
+            assert!(failure.message.ends_with(&format!(": thread {i}")));
+        }
+
+        // panics outside of `catch_panic` are left to the previous hook.
+        let _ = std::panic::catch_unwind(|| panic!("not caught"));
+        assert_eq!(
+            super::LAST_PANIC.with(|last| last.borrow_mut().take()),
+            None
+        );
+    }
+
+    #[test]
+    fn converts_answers() {
+        assert_eq!(Some(42).to_answer(), Ok(Some("42".into())));
+        assert_eq!(None::<u32>.to_answer(), Ok(None));
+        assert_eq!(
+            Ok::<_, ParseIntError>(42).to_answer(),
+            Ok(Some("42".into()))
+        );
+
+        let failure = "x".parse::<u32>().to_answer().unwrap_err();
+        assert_eq!(failure.message, "invalid digit found in string");
+        assert_eq!(failure.location, None);
+
+        let input = "1\n2x\n";
+        let error = InputError::at(input, &input[3..], "invalid digit found in string");
+        let failure = Err::<u32, _>(error.clone()).to_answer().unwrap_err();
+        assert_eq!(
+            failure.message,
+            "line 2, column 2: invalid digit found in string"
+        );
+        assert_eq!(failure.location, Some(error.location));
+    }
+
+    #[test]
+    fn round_trips_failure_locations() {
+        let input = "1\n2x\n";
+        let report = PartReport {
+            failure: Some(Failure::from_error(&InputError::at(
+                input,
+                &input[3..],
+                "invalid digit",
+            ))),
+            ..PartReport::unfinished(puzzle!(2024, 1), 1)
+        };
+
+        let json = JsonValue::from(&report).stringify().unwrap();
+        assert!(json.contains(r#""status":"failed""#));
+
+        let parsed = PartReport::try_from(&JsonValue::from_str(&json).unwrap()).unwrap();
+        assert_eq!(parsed.failure, report.failure);
+    }
 }
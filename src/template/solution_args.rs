@@ -0,0 +1,231 @@
+/// Command-line options of a solution binary, which pick its input and the parts to run:
+///
+/// - `--input <path>` reads the input from a file, `--input -` from stdin.
+/// - `--example [N]` runs against an example, e.g. `data/2024/examples/01.txt` or `01-2.txt` for `--example 2`. Its
+///   [parameters](crate::template::params) apply.
+/// - `--part <1|2>` only runs one part.
+///
+/// Without these, the solution runs both parts against the puzzle input. `--format` and `--submit` are options of the
+/// [runner](crate::template::runner::RunOptions).
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::PathBuf,
+    process,
+};
+
+use crate::template::params::{read_example_params, PuzzleParams};
+use crate::template::{try_read_file, try_read_file_part, Puzzle};
+
+/// Where a solution reads its input from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input, e.g. `data/2024/inputs/01.txt`.
+    #[default]
+    Puzzle,
+    /// An example, optionally one of several numbered ones.
+    Example(Option<u8>),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Read the input of a puzzle from this source.
+    pub fn read(&self, puzzle: Puzzle) -> io::Result<String> {
+        match self {
+            InputSource::Puzzle => try_read_file("inputs", puzzle),
+            InputSource::Example(None) => try_read_file("examples", puzzle),
+            InputSource::Example(Some(n)) => try_read_file_part("examples", puzzle, *n),
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+
+    /// The parameters of the input. Examples may override the defaults, other inputs use them.
+    pub fn params<P: PuzzleParams>(&self, puzzle: Puzzle) -> Result<P, String> {
+        match self {
+            InputSource::Example(example) => read_example_params(puzzle, *example),
+            _ => Ok(P::default()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SolutionArgs {
+    pub input: InputSource,
+    /// The only part to run, [`None`] to run all parts of the solution.
+    pub part: Option<u8>,
+}
+
+impl SolutionArgs {
+    /// Read the options from the command-line arguments, exiting if they are invalid.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().skip(1).collect();
+
+        Self::parse(&args).unwrap_or_else(|e| {
+            eprintln!("Unexpected command-line input. {e}");
+            process::exit(1);
+        })
+    }
+
+    /// Parse the options, ignoring arguments that belong to the runner.
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        Self::parse_known(args).map(|(parsed, _)| parsed)
+    }
+
+    /// Parse the options and return the other arguments, e.g. to pass them on to `cargo solve`.
+    pub fn parse_known(args: &[String]) -> Result<(Self, Vec<String>), String> {
+        let mut parsed = SolutionArgs::default();
+        let mut rest = vec![];
+        let mut args = args.iter().peekable();
+
+        while let Some(arg) = args.next() {
+            let input = match arg.as_str() {
+                "--input" => match args.next().map(String::as_str) {
+                    Some("-") => InputSource::Stdin,
+                    Some(path) => InputSource::File(path.into()),
+                    None => return Err("Format: --input <path|->".into()),
+                },
+                "--example" => {
+                    // the number of the example is optional.
+                    let n = args.next_if(|n| !n.starts_with('-')).map(|n| {
+                        n.parse::<u8>()
+                            .map_err(|_| format!("Invalid example `{n}`. Format: --example [N]"))
+                    });
+                    InputSource::Example(n.transpose()?)
+                }
+                "--part" => {
+                    parsed.part = match args.next().map(String::as_str) {
+                        Some("1") => Some(1),
+                        Some("2") => Some(2),
+                        _ => return Err("Format: --part <1|2>".into()),
+                    };
+                    continue;
+                }
+                _ => {
+                    rest.push(arg.clone());
+                    continue;
+                }
+            };
+
+            if parsed.input != InputSource::Puzzle {
+                return Err("Pass either `--input` or `--example`, once.".into());
+            }
+            parsed.input = input;
+        }
+
+        Ok((parsed, rest))
+    }
+
+    /// The command-line arguments of these options, the reverse of [`SolutionArgs::parse`].
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+
+        match &self.input {
+            InputSource::Puzzle => {}
+            InputSource::Example(example) => {
+                args.push("--example".into());
+                args.extend(example.map(|n| n.to_string()));
+            }
+            InputSource::File(path) => {
+                args.extend(["--input".into(), path.display().to_string()]);
+            }
+            InputSource::Stdin => args.extend(["--input".into(), "-".into()]),
+        }
+
+        if let Some(part) = self.part {
+            args.extend(["--part".into(), part.to_string()]);
+        }
+
+        args
+    }
+
+    /// Read the input of a puzzle, exiting if it can't be read.
+    pub fn read_input(&self, puzzle: Puzzle) -> String {
+        self.input.read(puzzle).unwrap_or_else(|e| {
+            eprintln!("Could not read the input: {e}");
+            process::exit(1);
+        })
+    }
+
+    /// Read the parameters of the input, exiting if they are invalid.
+    pub fn read_params<P: PuzzleParams>(&self, puzzle: Puzzle) -> P {
+        self.input.params(puzzle).unwrap_or_else(|e| {
+            eprintln!("Invalid parameters: {e}");
+            process::exit(1);
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{InputSource, SolutionArgs};
+
+    fn parse(args: &[&str]) -> Result<SolutionArgs, String> {
+        let args: Vec<String> = args.iter().map(ToString::to_string).collect();
+        SolutionArgs::parse(&args)
+    }
+
+    #[test]
+    fn parses_inputs() {
+        assert_eq!(parse(&[]), Ok(SolutionArgs::default()));
+        assert_eq!(
+            parse(&["--input", "stress.txt"]).unwrap().input,
+            InputSource::File("stress.txt".into())
+        );
+        assert_eq!(parse(&["--input", "-"]).unwrap().input, InputSource::Stdin);
+        assert_eq!(
+            parse(&["--example"]).unwrap().input,
+            InputSource::Example(None)
+        );
+        assert_eq!(
+            parse(&["--example", "2", "--format", "json"])
+                .unwrap()
+                .input,
+            InputSource::Example(Some(2))
+        );
+        assert_eq!(
+            parse(&["--example", "--part", "2"]).unwrap(),
+            SolutionArgs {
+                input: InputSource::Example(None),
+                part: Some(2),
+            }
+        );
+    }
+
+    #[test]
+    fn round_trips_args() {
+        for args in [
+            &["--input", "-", "--part", "1"][..],
+            &["--example", "2"],
+            &["--example"],
+            &["--input", "stress.txt"],
+            &[],
+        ] {
+            assert_eq!(parse(args).unwrap().to_args(), args);
+        }
+
+        let args: Vec<String> = ["--release", "--example", "--submit", "1"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        let (parsed, rest) = SolutionArgs::parse_known(&args).unwrap();
+        assert_eq!(parsed.input, InputSource::Example(None));
+        assert_eq!(rest, ["--release", "--submit", "1"]);
+    }
+
+    #[test]
+    fn rejects_invalid_options() {
+        assert!(parse(&["--input"]).is_err());
+        assert!(parse(&["--example", "x"]).is_err());
+        assert!(parse(&["--example", "--input", "a.txt"]).is_err());
+        assert!(parse(&["--part", "3"]).is_err());
+        assert!(parse(&["--part"]).is_err());
+    }
+}
@@ -0,0 +1,479 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{Error, ErrorKind},
+    path::Path,
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{data_dir, Day, Year};
+
+static SUBMISSIONS_FILE_NAME: &str = "submissions.json";
+
+/// The verdict of Advent of Code on a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited,
+    /// The response could not be recognized, e.g. because the part was already solved.
+    Unknown,
+}
+
+impl Verdict {
+    /// Parse the verdict from the response to a submission.
+    pub fn from_response(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            Verdict::Correct
+        } else if response.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if response.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if response.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if response.contains("You gave an answer too recently") {
+            Verdict::RateLimited
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::RateLimited => "rate_limited",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.as_str().replace('_', " "))
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Verdict::Correct,
+            Verdict::Wrong,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::RateLimited,
+            Verdict::Unknown,
+        ]
+        .into_iter()
+        .find(|verdict| verdict.as_str() == s)
+        .ok_or(format!("unknown verdict `{s}`."))
+    }
+}
+
+/// Represents a single submitted answer.
+#[derive(Clone, Debug)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub verdict: Verdict,
+}
+
+/// Reason for refusing to submit an answer, derived from earlier submissions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved { answer: String },
+    AlreadySubmitted { verdict: Verdict },
+    AboveTooHigh { answer: String },
+    BelowTooLow { answer: String },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved { answer } => {
+                write!(f, "this part was already solved with answer {answer}.")
+            }
+            Refusal::AlreadySubmitted { verdict } => {
+                write!(f, "this answer was already submitted and was {verdict}.")
+            }
+            Refusal::AboveTooHigh { answer } => {
+                write!(f, "this answer is not below {answer}, which was too high.")
+            }
+            Refusal::BelowTooLow { answer } => {
+                write!(f, "this answer is not above {answer}, which was too low.")
+            }
+        }
+    }
+}
+
+/// Ledger of all submitted answers.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Dehydrate submissions to the JSON file of a year.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(data_dir(year).join(SUBMISSIONS_FILE_NAME))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate submissions from the JSON file of a year. If not present, returns empty submissions.
+    /// Fails if the file can't be read or parsed, so that a broken ledger is not mistaken for an empty one.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        Self::read_from_path(&data_dir(year).join(SUBMISSIONS_FILE_NAME))
+    }
+
+    fn read_from_path(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(json) => Submissions::try_from(json).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Submissions::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    /// Add a submission to the ledger.
+    pub fn record(&mut self, submission: Submission) {
+        self.data.push(submission);
+    }
+
+    /// Check an answer against earlier submissions for the same part before submitting it.
+    /// Rate limited submissions say nothing about the answer and are ignored.
+    pub fn check(&self, day: Day, part: u8, answer: &str) -> Result<(), Refusal> {
+        let previous: Vec<&Submission> = self
+            .data
+            .iter()
+            .filter(|s| s.day == day && s.part == part && s.verdict != Verdict::RateLimited)
+            .collect();
+
+        if let Some(solved) = previous.iter().find(|s| s.verdict == Verdict::Correct) {
+            return Err(Refusal::AlreadySolved {
+                answer: solved.answer.clone(),
+            });
+        }
+
+        if let Some(duplicate) = previous.iter().find(|s| s.answer == answer) {
+            return Err(Refusal::AlreadySubmitted {
+                verdict: duplicate.verdict,
+            });
+        }
+
+        // NOTE: bounds only apply to numeric answers.
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        for submission in previous {
+            let Ok(previous_value) = submission.answer.parse::<i128>() else {
+                continue;
+            };
+
+            match submission.verdict {
+                Verdict::TooHigh if value >= previous_value => {
+                    return Err(Refusal::AboveTooHigh {
+                        answer: submission.answer.clone(),
+                    });
+                }
+                Verdict::TooLow if value <= previous_value => {
+                    return Err(Refusal::BelowTooLow {
+                        answer: submission.answer.clone(),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Submissions> for JsonValue {
+    fn from(value: Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.as_str().into()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .and_then(|&part| u8::try_from(part as i64).ok())
+            .ok_or("Expected submission.part to be a part number.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected submission.timestamp to be a number.")?;
+
+        let verdict = json
+            .get("verdict")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.verdict to be a string.")?
+            .parse()?;
+
+        Ok(Submission {
+            day,
+            part,
+            answer: answer.clone(),
+            timestamp: timestamp as u64,
+            verdict,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::day;
+
+    use super::{Refusal, Submission, Submissions, Verdict};
+
+    fn submission(part: u8, answer: &str, verdict: Verdict) -> Submission {
+        Submission {
+            day: day!(5),
+            part,
+            answer: answer.into(),
+            timestamp: 1_733_400_000,
+            verdict,
+        }
+    }
+
+    mod verdict {
+        use super::super::Verdict;
+
+        #[test]
+        fn parses_captured_responses() {
+            let cases = [
+                (
+                    include_str!("fixtures/submit/correct.txt"),
+                    Verdict::Correct,
+                ),
+                (include_str!("fixtures/submit/wrong.txt"), Verdict::Wrong),
+                (
+                    include_str!("fixtures/submit/too_high.txt"),
+                    Verdict::TooHigh,
+                ),
+                (include_str!("fixtures/submit/too_low.txt"), Verdict::TooLow),
+                (
+                    include_str!("fixtures/submit/rate_limited.txt"),
+                    Verdict::RateLimited,
+                ),
+                (
+                    include_str!("fixtures/submit/already_solved.txt"),
+                    Verdict::Unknown,
+                ),
+            ];
+
+            for (response, verdict) in cases {
+                assert_eq!(Verdict::from_response(response), verdict);
+            }
+        }
+
+        #[test]
+        fn round_trips_verdicts() {
+            for verdict in [Verdict::TooHigh, Verdict::RateLimited, Verdict::Unknown] {
+                assert_eq!(verdict.as_str().parse::<Verdict>(), Ok(verdict));
+            }
+        }
+    }
+
+    mod check {
+        use super::{submission, Refusal, Submissions, Verdict};
+        use crate::day;
+
+        #[test]
+        fn allows_new_answers() {
+            let submissions = Submissions {
+                data: vec![submission(1, "100", Verdict::Wrong)],
+            };
+            assert_eq!(submissions.check(day!(5), 1, "101"), Ok(()));
+            assert_eq!(submissions.check(day!(5), 2, "100"), Ok(()));
+            assert_eq!(submissions.check(day!(6), 1, "100"), Ok(()));
+        }
+
+        #[test]
+        fn refuses_duplicates() {
+            let submissions = Submissions {
+                data: vec![submission(1, "abc", Verdict::Wrong)],
+            };
+            assert_eq!(
+                submissions.check(day!(5), 1, "abc"),
+                Err(Refusal::AlreadySubmitted {
+                    verdict: Verdict::Wrong
+                })
+            );
+        }
+
+        #[test]
+        fn refuses_solved_parts() {
+            let submissions = Submissions {
+                data: vec![submission(2, "42", Verdict::Correct)],
+            };
+            assert_eq!(
+                submissions.check(day!(5), 2, "43"),
+                Err(Refusal::AlreadySolved {
+                    answer: "42".into()
+                })
+            );
+        }
+
+        #[test]
+        fn refuses_answers_out_of_bounds() {
+            let submissions = Submissions {
+                data: vec![
+                    submission(1, "1000", Verdict::TooHigh),
+                    submission(1, "10", Verdict::TooLow),
+                ],
+            };
+            assert_eq!(
+                submissions.check(day!(5), 1, "1200"),
+                Err(Refusal::AboveTooHigh {
+                    answer: "1000".into()
+                })
+            );
+            assert_eq!(
+                submissions.check(day!(5), 1, "5"),
+                Err(Refusal::BelowTooLow {
+                    answer: "10".into()
+                })
+            );
+            assert_eq!(submissions.check(day!(5), 1, "500"), Ok(()));
+        }
+
+        #[test]
+        fn ignores_rate_limited_submissions() {
+            let submissions = Submissions {
+                data: vec![submission(1, "500", Verdict::RateLimited)],
+            };
+            assert_eq!(submissions.check(day!(5), 1, "500"), Ok(()));
+        }
+    }
+
+    mod serialization {
+        use super::{submission, Submissions, Verdict};
+        use tinyjson::JsonValue;
+
+        #[test]
+        fn round_trips_submissions() {
+            let submissions = Submissions {
+                data: vec![
+                    submission(1, "1000", Verdict::TooHigh),
+                    submission(2, "42", Verdict::Correct),
+                ],
+            };
+            let json = JsonValue::from(submissions).stringify().unwrap();
+            let parsed = Submissions::try_from(json).unwrap();
+            assert_eq!(parsed.data.len(), 2);
+            assert_eq!(parsed.data[0].verdict, Verdict::TooHigh);
+            assert_eq!(parsed.data[1].answer, "42");
+            assert_eq!(parsed.data[1].timestamp, 1_733_400_000);
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_malformed_submissions() {
+            let json = r#"{ "data": [{ "day": "05", "part": 1, "answer": "1", "timestamp": 0, "verdict": "maybe" }] }"#.to_string();
+            Submissions::try_from(json).unwrap();
+        }
+
+        #[test]
+        fn reads_missing_but_not_broken_ledgers() {
+            let path =
+                std::env::temp_dir().join(format!("submissions-{}.json", std::process::id()));
+
+            assert_eq!(Submissions::read_from_path(&path).unwrap().data.len(), 0);
+
+            std::fs::write(&path, r#"{ "data": [{ "day": "05" "#).unwrap();
+            let error = Submissions::read_from_path(&path).unwrap_err();
+            std::fs::remove_file(&path).unwrap();
+            assert!(error.ends_with("not valid JSON file."));
+        }
+    }
+}
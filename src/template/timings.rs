@@ -1,58 +1,196 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::HashMap,
+    fs,
+    io::Error,
+    process::Command,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{data_dir, Day, Year};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+static TIMINGS_FILE_NAME: &str = "timings.json";
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    /// Duration of parsing the input, for solutions that share the parsed input between their parts.
+    pub parse_nanos: Option<f64>,
+    pub part_1_nanos: Option<f64>,
+    pub part_2_nanos: Option<f64>,
+    pub part_1_stats: Option<BenchStats>,
+    pub part_2_stats: Option<BenchStats>,
+    /// Allocations of the parts, if they were counted with the `alloc-stats` feature.
+    pub part_1_allocs: Option<AllocStats>,
+    pub part_2_allocs: Option<AllocStats>,
+    /// Set if the part exceeded its time budget. Timed out parts have no duration.
+    pub part_1_timed_out: bool,
+    pub part_2_timed_out: bool,
     pub total_nanos: f64,
+    pub run: RunInfo,
+}
+
+/// Describes the run that produced a timing.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RunInfo {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Short hash of the checked out git commit, suffixed with `-dirty` if there were uncommitted changes.
+    pub commit: Option<String>,
+    /// Version of the compiler the solutions were built with.
+    pub toolchain: Option<String>,
+}
+
+impl RunInfo {
+    /// Describe a run happening right now.
+    pub fn current() -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        RunInfo {
+            timestamp,
+            commit: get_git_commit(),
+            toolchain: option_env!("AOC_RUSTC_VERSION").map(Into::into),
+        }
+    }
+}
+
+fn get_git_commit() -> Option<String> {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    let commit = git(&["rev-parse", "--short", "HEAD"])?;
+    let is_dirty = git(&["status", "--porcelain", "--untracked-files=no"])
+        .is_some_and(|status| !status.is_empty());
+
+    Some(if is_dirty {
+        format!("{commit}-dirty")
+    } else {
+        commit
+    })
+}
+
+/// Sample statistics of a benched solution part. All durations are in nanoseconds.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchStats {
+    pub samples: u64,
+    pub mean_nanos: f64,
+    pub median_nanos: f64,
+    pub min_nanos: f64,
+    pub std_dev_nanos: f64,
+}
+
+impl BenchStats {
+    /// Compute statistics over a set of sampled durations, [`None`] if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_unstable_by(f64::total_cmp);
+
+        let count = nanos.len();
+        let mean_nanos = nanos.iter().sum::<f64>() / count as f64;
+
+        let median_nanos = if count.is_multiple_of(2) {
+            (nanos[count / 2 - 1] + nanos[count / 2]) / 2.0
+        } else {
+            nanos[count / 2]
+        };
+
+        let std_dev_nanos = if count > 1 {
+            let variance =
+                nanos.iter().map(|x| (x - mean_nanos).powi(2)).sum::<f64>() / (count - 1) as f64;
+            variance.sqrt()
+        } else {
+            0.0
+        };
+
+        Some(BenchStats {
+            samples: count as u64,
+            mean_nanos,
+            median_nanos,
+            min_nanos: nanos[0],
+            std_dev_nanos,
+        })
+    }
+}
+
+/// Allocations of a solution part. Benched parts only count the allocations of their first run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    /// Sum of the sizes of all allocations.
+    pub bytes: u64,
+    /// The most memory the part held at once.
+    pub peak_bytes: u64,
 }
 
 /// Represents benchmark times for a set of days.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
 pub struct Timings {
+    /// The latest timing of each day.
     pub data: Vec<Timing>,
+    /// Timings of earlier runs, oldest first.
+    pub history: Vec<Timing>,
 }
 
 impl Timings {
-    /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    /// Dehydrate timings to the JSON file of a year.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(data_dir(year).join(TIMINGS_FILE_NAME))?;
         json.format_to(&mut file)
     }
 
-    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+    /// Rehydrate timings from the JSON file of a year. If not present, returns empty timings.
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(data_dir(year).join(TIMINGS_FILE_NAME))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
     }
 
     /// Merge two sets of timings, overwriting `self` with `other` if present.
+    /// Overwritten timings are moved to the history.
     pub fn merge(&self, new: &Self) -> Self {
         let mut data: Vec<Timing> = vec![];
+        let mut history = self.history.clone();
 
         for timing in &new.data {
             data.push(timing.clone());
         }
 
         for timing in &self.data {
-            if !data.iter().any(|t| t.day == timing.day) {
+            if data.iter().any(|t| t.day == timing.day) {
+                history.push(timing.clone());
+            } else {
                 data.push(timing.clone());
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
-        Timings { data }
+        data.sort_unstable_by_key(|a| a.day);
+        history.sort_by_key(|a| a.run.timestamp);
+        Timings { data, history }
+    }
+
+    /// Returns the latest and all earlier timings of a day, oldest first.
+    pub fn history_of(&self, day: Day) -> impl Iterator<Item = &Timing> {
+        self.history
+            .iter()
+            .chain(self.data.iter())
+            .filter(move |t| t.day == day)
     }
 
     /// Sum up total duration of timings as millis.
@@ -63,7 +201,7 @@ impl Timings {
     pub fn is_day_complete(&self, day: Day) -> bool {
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| t.day == day && t.part_1_nanos.is_some() && t.part_2_nanos.is_some())
     }
 }
 
@@ -78,6 +216,11 @@ impl From<Timings> for JsonValue {
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
         );
 
+        map.insert(
+            "history".into(),
+            JsonValue::Array(value.history.iter().map(JsonValue::from).collect()),
+        );
+
         JsonValue::Object(map)
     }
 }
@@ -88,19 +231,33 @@ impl TryFrom<String> for Timings {
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
 
-        let json_data = json
+        let json = json
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        let json_data = json
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
             .ok_or("expected `json.data` to be an array.")?;
 
+        // NOTE: the history is optional so that timings stored before it was introduced still load.
+        let json_history = match json.get("history") {
+            None => &vec![],
+            Some(history) => history
+                .get::<Vec<JsonValue>>()
+                .ok_or("expected `json.history` to be an array.")?,
+        };
+
         Ok(Timings {
             data: json_data
                 .iter()
                 .map(Timing::try_from)
                 .collect::<Result<_, _>>()?,
+            history: json_history
+                .iter()
+                .map(Timing::try_from)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -114,23 +271,88 @@ impl From<&Timing> for JsonValue {
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
+        map.insert(
+            "parse_nanos".into(),
+            value.parse_nanos.map_or(JsonValue::Null, JsonValue::Number),
+        );
 
         map.insert(
-            "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            "part_1_nanos".into(),
+            value
+                .part_1_nanos
+                .map_or(JsonValue::Null, JsonValue::Number),
         );
 
         map.insert(
-            "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            "part_2_nanos".into(),
+            value
+                .part_2_nanos
+                .map_or(JsonValue::Null, JsonValue::Number),
+        );
+
+        map.insert(
+            "part_1_stats".into(),
+            value
+                .part_1_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_2_stats".into(),
+            value
+                .part_2_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_1_allocs".into(),
+            value
+                .part_1_allocs
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_2_allocs".into(),
+            value
+                .part_2_allocs
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_1_timed_out".into(),
+            JsonValue::Boolean(value.part_1_timed_out),
+        );
+
+        map.insert(
+            "part_2_timed_out".into(),
+            JsonValue::Boolean(value.part_2_timed_out),
+        );
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.run.timestamp as f64),
+        );
+
+        map.insert(
+            "commit".into(),
+            value
+                .run
+                .commit
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert(
+            "toolchain".into(),
+            value
+                .run
+                .toolchain
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
         );
 
         JsonValue::Object(map)
@@ -151,26 +373,217 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|day| Day::from_str(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+        // NOTE: only solutions that share their parsed input have a parse timing.
+        let parse_nanos = match json.get("parse_nanos") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => Some(
+                v.get::<f64>()
+                    .copied()
+                    .ok_or("Expected timing.parse_nanos to be null or a number.")?,
+            ),
+        };
 
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+        let part_1_nanos = get_part_nanos(json, 1)?;
+        let part_2_nanos = get_part_nanos(json, 2)?;
 
         let total_nanos = json
             .get("total_nanos")
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // NOTE: stats are optional so that timings stored before benching was introduced still load.
+        let part_1_stats = match json.get("part_1_stats") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => Some(BenchStats::try_from(v)?),
+        };
+
+        let part_2_stats = match json.get("part_2_stats") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => Some(BenchStats::try_from(v)?),
+        };
+
+        // NOTE: allocations are only counted with the `alloc-stats` feature.
+        let part_1_allocs = match json.get("part_1_allocs") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => Some(AllocStats::try_from(v)?),
+        };
+
+        let part_2_allocs = match json.get("part_2_allocs") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => Some(AllocStats::try_from(v)?),
+        };
+
+        // NOTE: timeouts are optional as well, timings stored before budgets were introduced have none.
+        let get_bool = |key: &str| match json.get(key) {
+            None | Some(JsonValue::Null) => Ok(false),
+            Some(v) => v
+                .get::<bool>()
+                .copied()
+                .ok_or(format!("Expected timing.{key} to be a boolean.")),
+        };
+
+        // NOTE: run info is optional as well, timings stored before it was introduced have none.
+        let get_string = |key: &str| match json.get(key) {
+            None | Some(JsonValue::Null) => Ok(None),
+            Some(v) => v
+                .get::<String>()
+                .cloned()
+                .map(Some)
+                .ok_or(format!("Expected timing.{key} to be null or string.")),
+        };
+
+        let timestamp = match json.get("timestamp") {
+            None | Some(JsonValue::Null) => 0,
+            Some(v) => v
+                .get::<f64>()
+                .map(|&timestamp| timestamp as u64)
+                .ok_or("Expected timing.timestamp to be a number.")?,
+        };
+
         Ok(Timing {
             day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
+            parse_nanos,
+            part_1_nanos,
+            part_2_nanos,
+            part_1_stats,
+            part_2_stats,
+            part_1_allocs,
+            part_2_allocs,
+            part_1_timed_out: get_bool("part_1_timed_out")?,
+            part_2_timed_out: get_bool("part_2_timed_out")?,
             total_nanos,
+            run: RunInfo {
+                timestamp,
+                commit: get_string("commit")?,
+                toolchain: get_string("toolchain")?,
+            },
+        })
+    }
+}
+
+/// Read the duration of a part in nanoseconds.
+/// Timings stored before durations were typed hold a display string like `74.1ms` under `part_<n>` instead,
+/// which is migrated when read.
+fn get_part_nanos(json: &HashMap<String, JsonValue>, part: u8) -> Result<Option<f64>, String> {
+    if let Some(value) = json.get(&format!("part_{part}_nanos")) {
+        return match value {
+            JsonValue::Null => Ok(None),
+            JsonValue::Number(nanos) => Ok(Some(*nanos)),
+            _ => Err(format!(
+                "Expected timing.part_{part}_nanos to be null or a number."
+            )),
+        };
+    }
+
+    match json.get(&format!("part_{part}")) {
+        Some(JsonValue::Null) => Ok(None),
+        Some(JsonValue::String(duration)) => parse_duration_nanos(duration)
+            .map(Some)
+            .ok_or(format!("Expected timing.part_{part} to be a duration.")),
+        _ => Err(format!(
+            "Expected timing.part_{part}_nanos to be null or a number."
+        )),
+    }
+}
+
+/// Parse a duration in the debug format of [`Duration`], e.g. `74.1ms`, to nanoseconds.
+fn parse_duration_nanos(duration: &str) -> Option<f64> {
+    let unit_start = duration.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+    let (value, unit) = duration.split_at(unit_start);
+
+    let factor = match unit {
+        "ns" => 1.0,
+        "µs" => 1e3,
+        "ms" => 1e6,
+        "s" => 1e9,
+        _ => return None,
+    };
+
+    value.parse::<f64>().ok().map(|value| value * factor)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&BenchStats> for JsonValue {
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("mean_nanos".into(), JsonValue::Number(value.mean_nanos));
+        map.insert("median_nanos".into(), JsonValue::Number(value.median_nanos));
+        map.insert("min_nanos".into(), JsonValue::Number(value.min_nanos));
+        map.insert(
+            "std_dev_nanos".into(),
+            JsonValue::Number(value.std_dev_nanos),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing stats to be a JSON object.")?;
+
+        let get_number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected timing stats.{key} to be a number."))
+        };
+
+        Ok(BenchStats {
+            samples: get_number("samples")? as u64,
+            mean_nanos: get_number("mean_nanos")?,
+            median_nanos: get_number("median_nanos")?,
+            min_nanos: get_number("min_nanos")?,
+            std_dev_nanos: get_number("std_dev_nanos")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&AllocStats> for JsonValue {
+    fn from(value: &AllocStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "allocations".into(),
+            JsonValue::Number(value.allocations as f64),
+        );
+        map.insert("bytes".into(), JsonValue::Number(value.bytes as f64));
+        map.insert(
+            "peak_bytes".into(),
+            JsonValue::Number(value.peak_bytes as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for AllocStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected allocation stats to be a JSON object.")?;
+
+        let get_number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .map(|n| n as u64)
+                .ok_or(format!("Expected allocation stats.{key} to be a number."))
+        };
+
+        Ok(AllocStats {
+            allocations: get_number("allocations")?,
+            bytes: get_number("bytes")?,
+            peak_bytes: get_number("peak_bytes")?,
         })
     }
 }
@@ -181,30 +594,55 @@ impl TryFrom<&JsonValue> for Timing {
 mod tests {
     use crate::day;
 
-    use super::{Timing, Timings};
+    use super::{RunInfo, Timing, Timings};
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    parse_nanos: None,
+                    part_1_nanos: Some(10_000_000_f64),
+                    part_2_nanos: Some(20_000_000_f64),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
+                    part_1_timed_out: false,
+                    part_2_timed_out: false,
                     total_nanos: 3e+10,
+                    run: RunInfo::default(),
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    parse_nanos: None,
+                    part_1_nanos: Some(30_000_000_f64),
+                    part_2_nanos: Some(40_000_000_f64),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
+                    part_1_timed_out: false,
+                    part_2_timed_out: false,
                     total_nanos: 7e+10,
+                    run: RunInfo::default(),
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: None,
+                    parse_nanos: None,
+                    part_1_nanos: Some(40_000_000_f64),
+                    part_2_nanos: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
+                    part_1_timed_out: false,
+                    part_2_timed_out: false,
                     total_nanos: 4e+10,
+                    run: RunInfo::default(),
                 },
             ],
+            history: vec![],
         }
     }
 
@@ -218,11 +656,41 @@ mod tests {
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
-            assert_eq!(timing.part_2, None);
+            assert_eq!(timing.part_1_nanos, Some(1_000_000_f64));
+            assert_eq!(timing.part_2_nanos, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_part_nanos() {
+            let json = r#"{ "data": [{ "day": "01", "part_1_nanos": 74130, "part_2_nanos": null, "total_nanos": 74130 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.part_1_nanos, Some(74_130_f64));
+            assert_eq!(timing.part_2_nanos, None);
+        }
+
+        #[test]
+        fn migrates_duration_strings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "166.0ns", "part_2": "74.13µs", "total_nanos": 0 }, { "day": "02", "part_1": "1.5s", "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].part_1_nanos, Some(166_f64));
+            assert!((timings.data[0].part_2_nanos.unwrap() - 74_130_f64).abs() < 1e-6);
+            assert_eq!(timings.data[1].part_1_nanos, Some(1_500_000_000_f64));
+            assert_eq!(timings.data[1].part_2_nanos, None);
+        }
+
+        #[test]
+        fn handles_json_bench_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "part_1_stats": { "samples": 10, "mean_nanos": 1000000, "median_nanos": 900000, "min_nanos": 800000, "std_dev_nanos": 5000 }, "part_2_stats": null, "total_nanos": 1000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1_stats.as_ref().unwrap();
+            assert_eq!(stats.samples, 10);
+            assert_eq!(stats.median_nanos, 900_000_f64);
+            assert_eq!(timing.part_2_stats, None);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -247,9 +715,63 @@ mod tests {
 
     mod serialization {
         use super::get_mock_timings;
+        use crate::template::timings::{AllocStats, BenchStats, RunInfo, Timings};
         use std::collections::HashMap;
         use tinyjson::JsonValue;
 
+        #[test]
+        fn round_trips_bench_stats() {
+            let mut timings = get_mock_timings();
+            let stats = BenchStats {
+                samples: 100,
+                mean_nanos: 10.0,
+                median_nanos: 9.0,
+                min_nanos: 8.0,
+                std_dev_nanos: 1.5,
+            };
+            timings.data[0].part_1_stats = Some(stats.clone());
+
+            let json = JsonValue::from(timings).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data[0].part_1_stats, Some(stats));
+            assert_eq!(parsed.data[0].part_2_stats, None);
+        }
+
+        #[test]
+        fn round_trips_alloc_stats() {
+            let mut timings = get_mock_timings();
+            let allocs = AllocStats {
+                allocations: 1204,
+                bytes: 65_536,
+                peak_bytes: 16_384,
+            };
+            timings.data[1].part_2_allocs = Some(allocs.clone());
+
+            let json = JsonValue::from(timings).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data[1].part_2_allocs, Some(allocs));
+            assert_eq!(parsed.data[1].part_1_allocs, None);
+        }
+
+        #[test]
+        fn round_trips_history() {
+            let mut timings = get_mock_timings();
+            let run = RunInfo {
+                timestamp: 1_733_011_200,
+                commit: Some("abc1234-dirty".into()),
+                toolchain: Some("rustc 1.83.0".into()),
+            };
+            timings.data[0].run = run.clone();
+            timings.history.push(timings.data[1].clone());
+
+            let json = JsonValue::from(timings).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data[0].run, run);
+            assert_eq!(parsed.data[1].run, RunInfo::default());
+            assert_eq!(parsed.history.len(), 1);
+            assert_eq!(parsed.history[0].day, parsed.data[1].day);
+        }
+
         #[test]
         fn serializes_timings() {
             let timings = get_mock_timings();
@@ -268,10 +790,42 @@ mod tests {
         }
     }
 
+    mod bench_stats {
+        use crate::template::timings::BenchStats;
+        use std::time::Duration;
+
+        #[test]
+        fn computes_statistics() {
+            let samples: Vec<Duration> = [40, 10, 30, 20]
+                .into_iter()
+                .map(Duration::from_nanos)
+                .collect();
+            let stats = BenchStats::from_samples(&samples).unwrap();
+            assert_eq!(stats.samples, 4);
+            assert_eq!(stats.mean_nanos, 25.0);
+            assert_eq!(stats.median_nanos, 25.0);
+            assert_eq!(stats.min_nanos, 10.0);
+            assert!((stats.std_dev_nanos - 12.909_944).abs() < 1e-6);
+        }
+
+        #[test]
+        fn handles_single_sample() {
+            let stats = BenchStats::from_samples(&[Duration::from_nanos(7)]).unwrap();
+            assert_eq!(stats.samples, 1);
+            assert_eq!(stats.median_nanos, 7.0);
+            assert_eq!(stats.std_dev_nanos, 0.0);
+        }
+
+        #[test]
+        fn handles_no_samples() {
+            assert_eq!(BenchStats::from_samples(&[]), None);
+        }
+    }
+
     mod is_day_complete {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::timings::{RunInfo, Timing, Timings},
         };
 
         #[test]
@@ -279,13 +833,22 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
+                    parse_nanos: None,
+                    part_1_nanos: Some(1_000_000_f64),
+                    part_2_nanos: Some(2_000_000_f64),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
+                    part_1_timed_out: false,
+                    part_2_timed_out: false,
                     total_nanos: 3_000_000_000_f64,
+                    run: RunInfo::default(),
                 }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -293,13 +856,22 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: None,
+                    parse_nanos: None,
+                    part_1_nanos: Some(1_000_000_f64),
+                    part_2_nanos: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
+                    part_1_timed_out: false,
+                    part_2_timed_out: false,
                     total_nanos: 1_000_000_000_f64,
+                    run: RunInfo::default(),
                 }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -307,20 +879,29 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: None,
-                    part_2: None,
+                    parse_nanos: None,
+                    part_1_nanos: None,
+                    part_2_nanos: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
+                    part_1_timed_out: false,
+                    part_2_timed_out: false,
                     total_nanos: 0.0,
+                    run: RunInfo::default(),
                 }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
     mod merge {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::timings::{RunInfo, Timing, Timings},
         };
 
         use super::get_mock_timings;
@@ -331,10 +912,19 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(3),
-                    part_1: None,
-                    part_2: None,
+                    parse_nanos: None,
+                    part_1_nanos: None,
+                    part_2_nanos: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
+                    part_1_timed_out: false,
+                    part_2_timed_out: false,
                     total_nanos: 0_f64,
+                    run: RunInfo::default(),
                 }],
+                history: vec![],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
@@ -351,10 +941,19 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(2),
-                    part_1: None,
-                    part_2: None,
+                    parse_nanos: None,
+                    part_1_nanos: None,
+                    part_2_nanos: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
+                    part_1_timed_out: false,
+                    part_2_timed_out: false,
                     total_nanos: 0_f64,
+                    run: RunInfo::default(),
                 }],
+                history: vec![],
             };
             let merged = timings.merge(&other);
 
@@ -365,6 +964,40 @@ mod tests {
             assert_eq!(merged.data[2].day, day!(4));
         }
 
+        #[test]
+        fn keeps_history_of_overwritten_timings() {
+            let timings = get_mock_timings();
+
+            let other = Timings {
+                data: vec![Timing {
+                    day: day!(2),
+                    parse_nanos: None,
+                    part_1_nanos: None,
+                    part_2_nanos: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_allocs: None,
+                    part_2_allocs: None,
+                    part_1_timed_out: false,
+                    part_2_timed_out: false,
+                    total_nanos: 0_f64,
+                    run: RunInfo {
+                        timestamp: 10,
+                        ..RunInfo::default()
+                    },
+                }],
+                history: vec![],
+            };
+            let merged = timings.merge(&other).merge(&other);
+
+            assert_eq!(merged.data.len(), 3);
+            assert_eq!(merged.history.len(), 2);
+            assert_eq!(merged.history[0].total_nanos, 7e+10);
+            assert_eq!(merged.history[1].run.timestamp, 10);
+            assert_eq!(merged.history_of(day!(2)).count(), 3);
+            assert_eq!(merged.history_of(day!(1)).count(), 1);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let timings = Timings::default();
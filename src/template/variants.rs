@@ -0,0 +1,212 @@
+/// Variants of a solution's parts, e.g. `part_two` and `part_two_bitset`, registered with `variants = [...]` in the
+/// `solution!` macro. They are benched against the same input and their answers are checked against the solution's
+/// own implementation of the part, the first variant.
+use std::time::Duration;
+
+use crate::template::registry::{Solution, Variant};
+use crate::template::run_multi::in_process;
+use crate::template::runner::{PartReport, SolutionReport};
+use crate::template::{Puzzle, ANSI_BOLD, ANSI_RESET};
+
+/// The report of a variant run against the input.
+#[derive(Clone, Debug)]
+pub struct VariantRun {
+    pub variant: &'static Variant,
+    pub report: SolutionReport,
+}
+
+impl VariantRun {
+    /// The report of the variant's part, [`None`] if it didn't run.
+    fn part(&self) -> Option<&PartReport> {
+        self.report
+            .parts
+            .iter()
+            .find(|part| part.part == self.variant.part)
+    }
+
+    /// The answer of the variant, [`None`] if it failed, timed out or didn't run.
+    fn answer(&self) -> Option<Option<&str>> {
+        let part = self.part()?;
+        let is_finished = part.failure.is_none() && !part.timed_out;
+        is_finished.then_some(part.answer.as_deref())
+    }
+
+    fn duration(&self) -> Option<Duration> {
+        self.answer()?;
+        self.part().map(|part| part.duration)
+    }
+}
+
+/// Bench each variant of a solution against an input. Each variant gets `budget` per stage.
+pub fn run_variants(solution: &Solution, input: &str, budget: Option<Duration>) -> Vec<VariantRun> {
+    solution
+        .variants
+        .iter()
+        .filter_map(|variant| {
+            println!();
+            println!(
+                "{ANSI_BOLD}Day {} · {}{ANSI_RESET}",
+                solution.puzzle.day, variant.name
+            );
+            println!("------");
+
+            let report = in_process::run_variant(solution.puzzle, input.into(), variant, budget)?;
+            Some(VariantRun { variant, report })
+        })
+        .collect()
+}
+
+/// Whether a variant of a part gave a different answer than the first one, or failed.
+pub fn has_disagreements(runs: &[VariantRun]) -> bool {
+    [1, 2].into_iter().any(|part| {
+        let mut runs = runs.iter().filter(|run| run.variant.part == part);
+        let Some(own) = runs.next() else {
+            return false;
+        };
+
+        own.answer().is_none() || runs.any(|run| run.answer() != own.answer())
+    })
+}
+
+/// Print a table per part of the answers of its variants and their times relative to the first one.
+pub fn print_tables(puzzle: Puzzle, runs: &[VariantRun]) {
+    for part in [1, 2] {
+        let runs: Vec<_> = runs.iter().filter(|run| run.variant.part == part).collect();
+
+        let Some(own) = runs.first() else {
+            continue;
+        };
+
+        println!();
+        println!(
+            "{ANSI_BOLD}Day {} part {part} variants:{ANSI_RESET}",
+            puzzle.day
+        );
+        println!("| Variant | Answer | Time | Relative |");
+        println!("| :--- | :--- | ---: | ---: |");
+
+        for run in &runs {
+            let (time, relative) = match run.duration() {
+                Some(duration) => (
+                    format!("{duration:.1?}"),
+                    format_relative(duration, own.duration()),
+                ),
+                None => ("-".into(), "-".into()),
+            };
+
+            println!(
+                "| {} | {} | {time} | {relative} |",
+                run.variant.name,
+                format_cell(run, own)
+            );
+        }
+    }
+}
+
+/// Format the answer of a variant and whether it agrees with the first one, e.g. `✔ 11` or `✘ 12 (expected 11)`.
+fn format_cell(run: &VariantRun, own: &VariantRun) -> String {
+    let Some(part) = run.part() else {
+        // a failed or timed out parse keeps the part from running.
+        return if run.report.is_timed_out() {
+            "⏱ timed out".into()
+        } else {
+            "✘ failed".into()
+        };
+    };
+
+    if part.timed_out {
+        return "⏱ timed out".into();
+    }
+
+    if part.failure.is_some() {
+        return "✘ failed".into();
+    }
+
+    let answer = part.answer.as_deref().unwrap_or("nothing");
+
+    if std::ptr::eq(run, own) {
+        return answer.into();
+    }
+
+    match own.answer() {
+        Some(expected) if expected == run.answer().flatten() => format!("✔ {answer}"),
+        Some(expected) => format!("✘ {answer} (expected {})", expected.unwrap_or("nothing")),
+        None => answer.into(),
+    }
+}
+
+/// Format the time of a variant relative to the first one, e.g. `0.25x` if it takes a quarter of the time.
+fn format_relative(duration: Duration, own: Option<Duration>) -> String {
+    match own {
+        Some(own) if !own.is_zero() => {
+            format!("{:.2}x", duration.as_secs_f64() / own.as_secs_f64())
+        }
+        _ => "-".into(),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{format_cell, format_relative, has_disagreements, VariantRun};
+    use crate::puzzle;
+    use crate::template::registry::Variant;
+    use crate::template::runner::{PartReport, SolutionReport};
+
+    static VARIANTS: [Variant; 3] = [
+        Variant {
+            name: "part_two",
+            part: 2,
+        },
+        Variant {
+            name: "part_two_bitset",
+            part: 2,
+        },
+        Variant {
+            name: "part_two_walk",
+            part: 2,
+        },
+    ];
+
+    fn run(variant: usize, answer: &str, millis: u64) -> VariantRun {
+        VariantRun {
+            variant: &VARIANTS[variant],
+            report: SolutionReport {
+                parts: vec![PartReport {
+                    answer: Some(answer.into()),
+                    duration: Duration::from_millis(millis),
+                    ..PartReport::unfinished(puzzle!(2024, 20), 2)
+                }],
+                ..SolutionReport::default()
+            },
+        }
+    }
+
+    #[test]
+    fn checks_agreement() {
+        let runs = [run(0, "42", 10), run(1, "42", 5)];
+        assert!(!has_disagreements(&runs));
+        assert_eq!(format_cell(&runs[0], &runs[0]), "42");
+        assert_eq!(format_cell(&runs[1], &runs[0]), "✔ 42");
+
+        let runs = [run(0, "42", 10), run(1, "42", 5), run(2, "41", 5)];
+        assert!(has_disagreements(&runs));
+        assert_eq!(format_cell(&runs[2], &runs[0]), "✘ 41 (expected 42)");
+
+        let mut failed = run(1, "42", 5);
+        failed.report.parts[0].failure = Some("boom".into());
+        assert!(has_disagreements(&[run(0, "42", 10), failed.clone()]));
+        assert_eq!(format_cell(&failed, &runs[0]), "✘ failed");
+    }
+
+    #[test]
+    fn formats_relative_times() {
+        let own = Some(Duration::from_millis(10));
+        assert_eq!(format_relative(Duration::from_millis(10), own), "1.00x");
+        assert_eq!(format_relative(Duration::from_micros(2500), own), "0.25x");
+        assert_eq!(format_relative(Duration::from_millis(10), None), "-");
+    }
+}